@@ -1,32 +1,81 @@
 extern crate pg_interval;
 extern crate postgres_types;
 extern crate serde;
+extern crate serde_json;
+#[cfg(feature = "chrono")]
+extern crate chrono;
+#[cfg(feature = "sqlx")]
+extern crate sqlx;
+#[cfg(feature = "time")]
+extern crate time;
+#[cfg(feature = "diesel")]
+extern crate diesel;
 
 use std::error::Error;
 use std::str::FromStr;
+use std::time::Duration;
 use postgres_types::{accepts, FromSql, IsNull, to_sql_checked, ToSql, Type};
 use postgres_types::private::BytesMut;
 use serde::{Deserialize, Serialize};
-use serde::ser::{SerializeStruct};
-use std::convert::TryInto;
-use std::fmt::{Display, Formatter, Write};
+use serde::ser::{SerializeStruct, SerializeTuple};
+use std::convert::{TryFrom, TryInto};
+use std::iter::Sum;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    InvalidInterval,
+    InvalidTime,
+    InvalidYearMonth,
+    ParseInt,
+    ParseFloat,
+    /// A value parsed but does not fit its field; only reported by `Interval::new_strict`.
+    Overflow,
+}
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct ParseError {
     pg: pg_interval::ParseError,
+    overflow: bool,
+}
+
+impl ParseError {
+    fn overflow(message: &str) -> ParseError {
+        ParseError {
+            pg: pg_interval::ParseError::from_invalid_interval(message),
+            overflow: true,
+        }
+    }
+
+    pub fn kind(&self) -> ParseErrorKind {
+        if self.overflow {
+            return ParseErrorKind::Overflow;
+        }
+        match &self.pg {
+            pg_interval::ParseError::InvalidInterval(_) => ParseErrorKind::InvalidInterval,
+            pg_interval::ParseError::InvalidTime(_) => ParseErrorKind::InvalidTime,
+            pg_interval::ParseError::InvalidYearMonth(_) => ParseErrorKind::InvalidYearMonth,
+            pg_interval::ParseError::ParseIntErr(_) => ParseErrorKind::ParseInt,
+            pg_interval::ParseError::ParseFloatErr(_) => ParseErrorKind::ParseFloat,
+        }
+    }
 }
 
 impl From<pg_interval::ParseError> for ParseError {
     fn from(pg: pg_interval::ParseError) -> ParseError {
         ParseError {
-            pg
+            pg,
+            overflow: false,
         }
     }
 }
 
-impl Into<pg_interval::ParseError> for ParseError {
-    fn into(self) -> pg_interval::ParseError {
-        self.pg
+impl From<ParseError> for pg_interval::ParseError {
+    fn from(err: ParseError) -> pg_interval::ParseError {
+        err.pg
     }
 }
 
@@ -42,264 +91,3635 @@ impl Display for ParseError {
     }
 }
 
+// `pg_interval::ParseError` does not implement `Error` itself, so the numeric parse
+// error it wraps is the only source that can be exposed.
 impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.pg {
+            pg_interval::ParseError::ParseIntErr(err) => Some(err),
+            pg_interval::ParseError::ParseFloatErr(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
-#[derive(Debug)]
-pub struct Interval {
-    pg: pg_interval::Interval,
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConversionError {
+    message: String,
 }
 
-impl Interval {
-    pub fn new(interval: &str) -> Result<Interval, ParseError> {
-        Ok(Interval {
-            pg: pg_interval::Interval::from_postgres(&interval)?,
-        })
+impl ConversionError {
+    fn new(message: &str) -> ConversionError {
+        ConversionError {
+            message: message.to_string(),
+        }
     }
+}
 
-    pub fn inner(&self) -> &pg_interval::Interval {
-        &self.pg
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
     }
+}
 
-    pub fn bytes(&self) -> Vec<u8> {
-        let mut buf = vec![0u8, 16];
-        buf[0..8].copy_from_slice(&self.pg.microseconds.to_be_bytes());
-        buf[8..12].copy_from_slice(&self.pg.days.to_be_bytes());
-        buf[12..16].copy_from_slice(&self.pg.months.to_be_bytes());
-        buf
-    }
+impl Error for ConversionError {
 }
 
-impl FromStr for Interval {
-    type Err = ParseError;
+#[derive(Debug, PartialEq, Eq)]
+pub struct ScaleError {
+    message: String,
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Interval::new(s)
+impl ScaleError {
+    fn new(message: &str) -> ScaleError {
+        ScaleError {
+            message: message.to_string(),
+        }
     }
 }
 
-impl Display for Interval {
+impl Display for ScaleError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let years = self.pg.months / 12;
-        let months = self.pg.months % 12;
-        let days = self.pg.days;
-        let hours = self.pg.microseconds / 3_600_000_000;
-        let minutes = (self.pg.microseconds % 3_600_000_000) / 60_000_000;
-        let seconds = (self.pg.microseconds % 60_000_000) / 1_000_000;
-        let milliseconds = self.pg.microseconds % 1_000_000 / 1_000;
-        let microseconds = self.pg.microseconds % 1_000;
-        let mut buf = String::new();
-        if years > 0 {
-            write!(buf, "{} years ", years)?;
-        }
-        if months > 0 {
-            write!(buf, "{} mons ", months)?;
-        }
-        if days > 0 {
-            write!(buf, "{} days ", days)?;
-        }
-        if hours > 0 {
-            write!(buf, "{} hours ", hours)?;
-        }
-        if minutes > 0 {
-            write!(buf, "{} minutes ", minutes)?;
-        }
-        if seconds > 0 {
-            write!(buf, "{} seconds ", seconds)?;
-        }
-        if milliseconds > 0 {
-            write!(buf, "{} milliseconds ", milliseconds)?;
-        }
-        if microseconds > 0 {
-            write!(buf, "{} microseconds ", microseconds)?;
-        }
-        if buf.is_empty() {
-            write!(buf, "0 seconds")
-        } else {
-            write!(f, "{}", &buf.as_str()[..buf.len() - 1])
-        }
+        write!(f, "{}", self.message)
     }
 }
 
-impl<'a> FromSql<'a> for Interval {
-    fn from_sql(_: &Type, raw: &[u8]) -> Result<Interval, Box<dyn Error + Sync + Send>> {
-        Ok(Interval {
-            pg: pg_interval::Interval {
-                months: i32::from_be_bytes(raw[12..16].try_into().unwrap()),
-                days: i32::from_be_bytes(raw[8..12].try_into().unwrap()),
-                microseconds: i64::from_be_bytes(raw[0..8].try_into().unwrap()),
-            }
-        })
+impl Error for ScaleError {
+}
+
+/// The `IntervalBuilder` unit a `BuildError` blames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BuildErrorUnit {
+    Years,
+    Months,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+}
+
+impl BuildErrorUnit {
+    fn name(self) -> &'static str {
+        match self {
+            BuildErrorUnit::Years => "years",
+            BuildErrorUnit::Months => "months",
+            BuildErrorUnit::Days => "days",
+            BuildErrorUnit::Hours => "hours",
+            BuildErrorUnit::Minutes => "minutes",
+            BuildErrorUnit::Seconds => "seconds",
+            BuildErrorUnit::Milliseconds => "milliseconds",
+            BuildErrorUnit::Microseconds => "microseconds",
+        }
     }
+}
 
-    accepts!(INTERVAL);
+/// Returned by `IntervalBuilder::try_build` when the accumulated amount does not fit
+/// one of the interval's fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildError {
+    unit: BuildErrorUnit,
 }
 
-impl ToSql for Interval {
-    fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
-        w.extend_from_slice(self.pg.microseconds.to_be_bytes().as_slice());
-        w.extend_from_slice(self.pg.days.to_be_bytes().as_slice());
-        w.extend_from_slice(self.pg.months.to_be_bytes().as_slice());
-        Ok(IsNull::No)
+impl BuildError {
+    /// The unit that pushed its field out of range. Where several units share a field,
+    /// as years and months do, this is the one contributing the most in the direction
+    /// of the overflow.
+    pub fn unit(&self) -> BuildErrorUnit {
+        self.unit
     }
+}
 
-    accepts!(INTERVAL);
-    to_sql_checked!();
+impl Display for BuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "interval {} out of range", self.unit.name())
+    }
 }
 
-impl Serialize for Interval {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: serde::Serializer,
-    {
-        let mut state = serializer.serialize_struct("Interval", 3)?;
-        state.serialize_field("m", &self.pg.months)?;
-        state.serialize_field("d", &self.pg.days)?;
-        state.serialize_field("us", &self.pg.microseconds)?;
-        state.end()
+impl Error for BuildError {
+}
+
+// Postgres compares intervals as if every month had 30 days and every day 24 hours.
+const DAYS_PER_MONTH: i128 = 30;
+const MICROSECONDS_PER_DAY: i128 = 86_400_000_000;
+
+/// The granularity `Interval::round_to` rounds the microseconds to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundUnit {
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+}
+
+impl RoundUnit {
+    fn microseconds(self) -> i64 {
+        match self {
+            RoundUnit::Microsecond => 1,
+            RoundUnit::Millisecond => 1_000,
+            RoundUnit::Second => 1_000_000,
+            RoundUnit::Minute => 60_000_000,
+            RoundUnit::Hour => 3_600_000_000,
+            RoundUnit::Day => 86_400_000_000,
+        }
     }
 }
 
-impl<'de> Deserialize<'de> for Interval {
-    fn deserialize<D>(deserializer: D) -> Result<Interval, D::Error>
-        where
-            D: serde::Deserializer<'de>,
-    {
-        struct IntervalVisitor;
+/// The field sets an interval column can be declared with, e.g. `INTERVAL DAY TO SECOND`,
+/// as used by `Interval::restrict_to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntervalFields {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    YearToMonth,
+    DayToHour,
+    DayToMinute,
+    DayToSecond,
+    HourToMinute,
+    HourToSecond,
+    MinuteToSecond,
+}
 
-        impl<'de> serde::de::Visitor<'de> for IntervalVisitor {
-            type Value = Interval;
+impl IntervalFields {
+    // The largest and smallest unit in the set, ranked from years (0) down to seconds (5).
+    fn range(self) -> (u8, u8) {
+        match self {
+            IntervalFields::Year => (0, 0),
+            IntervalFields::Month => (1, 1),
+            IntervalFields::Day => (2, 2),
+            IntervalFields::Hour => (3, 3),
+            IntervalFields::Minute => (4, 4),
+            IntervalFields::Second => (5, 5),
+            IntervalFields::YearToMonth => (0, 1),
+            IntervalFields::DayToHour => (2, 3),
+            IntervalFields::DayToMinute => (2, 4),
+            IntervalFields::DayToSecond => (2, 5),
+            IntervalFields::HourToMinute => (3, 4),
+            IntervalFields::HourToSecond => (3, 5),
+            IntervalFields::MinuteToSecond => (4, 5),
+        }
+    }
+}
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("a string representing an interval")
-            }
+/// Equality is field-wise, matching how Postgres stores intervals rather than the
+/// duration they span: `1 mons` and `30 days` are not equal.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Interval))]
+pub struct Interval {
+    pg: pg_interval::Interval,
+}
 
-            fn visit_map<V>(self, mut visitor: V) -> Result<Interval, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
-            {
-                let mut months = None;
-                let mut days = None;
-                let mut microseconds = None;
-
-                while let Some(key) = visitor.next_key()? {
-                    match key {
-                        "m" => {
-                            if months.is_some() {
-                                return Err(serde::de::Error::duplicate_field("m"));
-                            }
-                            months = Some(visitor.next_value()?);
-                        }
-                        "d" => {
-                            if days.is_some() {
-                                return Err(serde::de::Error::duplicate_field("d"));
-                            }
-                            days = Some(visitor.next_value()?);
-                        }
-                        "us" => {
-                            if microseconds.is_some() {
-                                return Err(serde::de::Error::duplicate_field("us"));
-                            }
-                            microseconds = Some(visitor.next_value()?);
-                        }
-                        _ => {
-                            return Err(serde::de::Error::unknown_field(key, &["m", "d", "us"]));
-                        }
-                    }
-                }
+impl Interval {
+    pub const ZERO: Interval = Interval::from_components(0, 0, 0);
 
-                let months = months.ok_or_else(|| serde::de::Error::missing_field("m"))?;
-                let days = days.ok_or_else(|| serde::de::Error::missing_field("d"))?;
-                let microseconds = microseconds.ok_or_else(|| serde::de::Error::missing_field("us"))?;
+    /// Every field at its largest value, the bound `saturating_add` pins to.
+    pub const MAX: Interval = Interval::from_components(i32::MAX, i32::MAX, i64::MAX);
 
-                Ok(Interval {
-                    pg: pg_interval::Interval {
-                        months,
-                        days,
-                        microseconds,
-                    }
-                })
+    /// Every field at its smallest value, the bound `saturating_sub` pins to.
+    pub const MIN: Interval = Interval::from_components(i32::MIN, i32::MIN, i64::MIN);
+
+    pub const fn from_components(months: i32, days: i32, microseconds: i64) -> Interval {
+        Interval {
+            pg: pg_interval::Interval {
+                months,
+                days,
+                microseconds,
             }
         }
+    }
 
-        deserializer.deserialize_struct("Interval", &["m", "d", "us"], IntervalVisitor)
+    pub const fn zero() -> Interval {
+        Interval::ZERO
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    pub const fn is_zero(&self) -> bool {
+        self.pg.months == 0 && self.pg.days == 0 && self.pg.microseconds == 0
+    }
 
-    #[test]
-    fn test_interval_from_str() {
-        let interval = Interval::new("1 mons 2 days 3 seconds").unwrap();
-        assert_eq!(interval.pg.months, 1);
-        assert_eq!(interval.pg.days, 2);
-        assert_eq!(interval.pg.microseconds, 3000000);
+    /// Whether the interval is a pure time duration with no months or days, as required
+    /// before treating it as an exact length of time.
+    pub const fn is_time_only(&self) -> bool {
+        self.pg.months == 0 && self.pg.days == 0
     }
 
-    #[test]
-    fn test_interval_from_sql() {
-        let interval = Interval::from_sql(&Type::INTERVAL, &[0, 0, 0, 0, 0, 45, 198, 192, 0, 0, 0, 2, 0, 0, 0, 1]).unwrap();
-        assert_eq!(interval.pg.months, 1);
-        assert_eq!(interval.pg.days, 2);
-        assert_eq!(interval.pg.microseconds, 3000000);
+    /// Whether the interval has no time part, only months and days.
+    pub const fn is_date_only(&self) -> bool {
+        self.pg.microseconds == 0
     }
 
-    #[test]
-    fn test_interval_to_sql() {
-        let interval = Interval {
-            pg: pg_interval::Interval {
-                months: 1,
-                days: 2,
-                microseconds: 3000000,
-            }
-        };
-        let mut buf = BytesMut::new();
-        interval.to_sql(&Type::INTERVAL, &mut buf).unwrap();
-        assert_eq!(buf.as_ref(), &[0, 0, 0, 0, 0, 45, 198, 192, 0, 0, 0, 2, 0, 0, 0, 1]);
+    pub fn builder() -> IntervalBuilder {
+        IntervalBuilder::new()
     }
 
-    #[test]
-    fn test_interval_display() {
-        let interval = Interval {
-            pg: pg_interval::Interval {
-                months: 14,
-                days: 3,
-                microseconds: 4 * 3600000000 + 5 * 60000000 + 6 * 1000000 + 7 * 1000 + 8,
+    pub const fn from_months(months: i32) -> Interval {
+        Interval::from_components(months, 0, 0)
+    }
+
+    pub const fn from_days(days: i32) -> Interval {
+        Interval::from_components(0, days, 0)
+    }
+
+    /// Saturates at `i64::MIN`/`i64::MAX` microseconds.
+    pub const fn from_seconds(seconds: i64) -> Interval {
+        Interval::from_components(0, 0, seconds.saturating_mul(1_000_000))
+    }
+
+    /// Saturates at `i64::MIN`/`i64::MAX` microseconds.
+    pub const fn from_millis(milliseconds: i64) -> Interval {
+        Interval::from_components(0, 0, milliseconds.saturating_mul(1_000))
+    }
+
+    /// Folds hours, minutes and seconds into the microseconds, returning `None` if the
+    /// total does not fit an `i64`.
+    pub fn from_hms(hours: i64, minutes: i64, seconds: i64) -> Option<Interval> {
+        let microseconds = hours.checked_mul(3_600_000_000)?
+            .checked_add(minutes.checked_mul(60_000_000)?)?
+            .checked_add(seconds.checked_mul(1_000_000)?)?;
+        Some(Interval::from_components(0, 0, microseconds))
+    }
+
+    /// Reverses `EXTRACT(EPOCH FROM interval)`, rounding the seconds to the nearest
+    /// microsecond and storing them in the microseconds field. NaN yields zero and values
+    /// beyond the `i64` range saturate.
+    pub fn from_epoch_seconds(seconds: f64) -> Interval {
+        Interval::from_components(0, 0, (seconds * 1_000_000.0).round() as i64)
+    }
+
+    /// Like `From<Duration>`, storing the duration in the microseconds field truncated to
+    /// whole microseconds, but fails instead of saturating when it exceeds `i64::MAX`
+    /// microseconds.
+    pub fn checked_from_std(duration: Duration) -> Result<Interval, ConversionError> {
+        i64::try_from(duration.as_micros())
+            .map(|microseconds| Interval::from_components(0, 0, microseconds))
+            .map_err(|_| ConversionError::new("std::time::Duration is out of range for an interval"))
+    }
+
+    /// Parses the Postgres text format, e.g. `1 year 2 mons 3 days`. Units may be written
+    /// plural, singular or abbreviated, as in `1 mon 2 hrs`, and values may carry a `+`
+    /// sign, so the output of a Postgres server parses as is, and so does `Display`, which
+    /// also writes milliseconds and microseconds. The time may also be written as a clock,
+    /// `[-]H:MM[:SS[.ffffff]]`, as in `3 days 04:05:06.5`, which is the form
+    /// `to_postgres_string` produces. A month or day count beyond the `i32` range is
+    /// clamped, as upstream does; `new_strict` rejects it instead.
+    pub fn new(interval: &str) -> Result<Interval, ParseError> {
+        Interval::parse(interval, false)
+    }
+
+    fn parse(interval: &str, strict: bool) -> Result<Interval, ParseError> {
+        // Upstream only reports overflow as these two kinds, which `new_strict` turns into
+        // `ParseErrorKind::Overflow`.
+        let out_of_range = |error: pg_interval::ParseError| match error {
+            pg_interval::ParseError::InvalidYearMonth(message) | pg_interval::ParseError::InvalidTime(message) if strict => {
+                ParseError::overflow(&message)
             }
+            error => ParseError::from(error),
         };
-        assert_eq!(format!("{}", interval), "1 years 2 mons 3 days 4 hours 5 minutes 6 seconds 7 milliseconds 8 microseconds");
-
-        let interval = Interval {
-            pg: pg_interval::Interval {
-                months: 1,
-                days: 2,
-                microseconds: 3 * 1000000,
+        let mut clock = None;
+        let mut tokens = Vec::new();
+        for token in interval.split_whitespace() {
+            if token.contains(':') {
+                if clock.replace(token).is_some() {
+                    return Err(pg_interval::ParseError::from_invalid_interval("Interval can only contain one clock.").into());
+                }
+                continue;
+            }
+            let token = match token.strip_prefix('+') {
+                Some(unsigned) if unsigned.starts_with(|c: char| c.is_ascii_digit() || c == '.') => unsigned,
+                _ => token,
+            };
+            // A value and unit may be written as one token such as `3days`.
+            match token.find(char::is_alphabetic) {
+                Some(split) if split > 0 => tokens.extend([&token[..split], Interval::canonical_unit(&token[split..])]),
+                _ => tokens.push(Interval::canonical_unit(token)),
+            }
+        }
+        if tokens.len() % 2 != 0 {
+            return Err(pg_interval::ParseError::from_invalid_interval("Invalid amount tokens were found.").into());
+        }
+        // Each pair is parsed on its own and the fields summed here, so a sum that leaves
+        // its field's range is caught rather than overflowing inside `pg_interval`.
+        let (mut months, mut days, mut microseconds) = (0i128, 0i128, 0i128);
+        let mut seen = Vec::new();
+        for pair in tokens.chunks_exact(2) {
+            let (value, unit) = (pair[0], pair[1]);
+            if seen.contains(&unit) {
+                return Err(pg_interval::ParseError::from_invalid_interval(&format!("Unknown or duplicate deliminator \"{}\"", unit)).into());
+            }
+            seen.push(unit);
+            let scale = match unit {
+                "milliseconds" => 1_000.0,
+                "microseconds" => 1.0,
+                _ => {
+                    // Upstream clamps a month or day value beyond `i32` without an error.
+                    let in_range = |value: f64| (f64::from(i32::MIN)..=f64::from(i32::MAX)).contains(&value.trunc());
+                    if strict && (unit == "mons" || unit == "days") && value.parse().is_ok_and(|value| !in_range(value)) {
+                        return Err(ParseError::overflow("Invalid month/day interval overflow detected."));
+                    }
+                    let part = pg_interval::Interval::from_postgres(&format!("{} {}", value, unit)).map_err(out_of_range)?;
+                    months += i128::from(part.months);
+                    days += i128::from(part.days);
+                    microseconds += i128::from(part.microseconds);
+                    continue;
+                }
+            };
+            let value = (value.parse::<f64>().map_err(pg_interval::ParseError::from)? * scale).round();
+            if !value.is_finite() || value.abs() >= 9.2e18 {
+                return Err(out_of_range(pg_interval::ParseError::from_time("Invalid time interval overflow detected.")));
             }
+            microseconds += value as i128;
+        }
+        if let Some(clock) = clock {
+            microseconds += Interval::parse_clock(clock)?;
+        }
+        let months = i32::try_from(months)
+            .map_err(|_| out_of_range(pg_interval::ParseError::from_year_month("Invalid year/month interval overflow detected.")))?;
+        let days = if strict {
+            i32::try_from(days).map_err(|_| ParseError::overflow("Invalid day interval overflow detected."))?
+        } else {
+            // Upstream clamps an out-of-range day count, and `new` keeps doing so.
+            days.clamp(i128::from(i32::MIN), i128::from(i32::MAX)) as i32
         };
-        assert_eq!(interval.to_string(), "1 mons 2 days 3 seconds");
+        let microseconds = i64::try_from(microseconds)
+            .map_err(|_| out_of_range(pg_interval::ParseError::from_time("Invalid time interval overflow detected.")))?;
+        Ok(Interval::from_components(months, days, microseconds))
     }
 
-    #[test]
-    fn test_interval_serialize() {
-        let interval = Interval {
-            pg: pg_interval::Interval {
-                months: 1,
-                days: 2,
-                microseconds: 3,
+    /// Maps the singular and abbreviated unit names Postgres writes and accepts onto the
+    /// plural ones `pg_interval` expects, leaving anything else untouched.
+    fn canonical_unit(unit: &str) -> &str {
+        match unit {
+            "year" | "yr" | "yrs" => "years",
+            "mon" | "month" | "months" => "mons",
+            "day" => "days",
+            "hour" | "hr" | "hrs" => "hours",
+            "minute" | "min" | "mins" => "minutes",
+            "second" | "sec" | "secs" => "seconds",
+            "millisecond" | "msec" | "msecs" => "milliseconds",
+            "microsecond" | "usec" | "usecs" => "microseconds",
+            unit => unit,
+        }
+    }
+
+    /// Reads a `[+-]H:MM[:SS[.ffffff]]` clock as microseconds, rounding any digits past the
+    /// sixth fractional one half away from zero. The caller checks the total against the
+    /// `i64` range.
+    fn parse_clock(clock: &str) -> Result<i128, ParseError> {
+        let invalid = || ParseError::from(pg_interval::ParseError::from_invalid_interval("Invalid clock, expected H:MM[:SS[.ffffff]]."));
+        let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        let (is_negative, clock) = match clock.as_bytes()[0] {
+            b'-' => (true, &clock[1..]),
+            b'+' => (false, &clock[1..]),
+            _ => (false, clock),
+        };
+        let mut parts = clock.split(':');
+        let hours = parts.next().filter(|part| is_digits(part)).ok_or_else(invalid)?;
+        let minutes = parts.next().filter(|part| is_digits(part) && part.len() <= 2).ok_or_else(invalid)?;
+        let (seconds, fraction) = match parts.next() {
+            Some(seconds) => match seconds.split_once('.') {
+                Some((seconds, fraction)) if is_digits(fraction) => (seconds, fraction),
+                Some(_) => return Err(invalid()),
+                None => (seconds, ""),
+            },
+            None => ("0", ""),
+        };
+        if parts.next().is_some() || !is_digits(seconds) || seconds.len() > 2 {
+            return Err(invalid());
+        }
+        let (minutes, seconds) = (minutes.parse::<i128>().map_err(|_| invalid())?, seconds.parse::<i128>().map_err(|_| invalid())?);
+        if minutes >= 60 || seconds >= 60 {
+            return Err(pg_interval::ParseError::from_time("Invalid clock, minutes and seconds must be below 60.").into());
+        }
+        // The hours are all digits, so parsing only fails when they are too large, and
+        // `u64::MAX` hours is still out of range for the caller's check.
+        let hours = i128::from(hours.parse::<u64>().unwrap_or(u64::MAX));
+        let mut micros = 0;
+        for (i, digit) in fraction.bytes().take(7).enumerate() {
+            let digit = i128::from(digit - b'0');
+            match i {
+                6 => micros += i128::from(digit >= 5),
+                _ => micros = micros * 10 + digit,
             }
+        }
+        micros *= 10i128.pow(6u32.saturating_sub(fraction.len() as u32));
+        let total = ((hours * 60 + minutes) * 60 + seconds) * 1_000_000 + micros;
+        Ok(if is_negative { -total } else { total })
+    }
+
+    /// Like `new`, but fails with a `ParseErrorKind::Overflow` error for any value that
+    /// does not fit its field. `new` reports an overflowing year, time unit or total as
+    /// `InvalidYearMonth` or `InvalidTime`, but clamps a month or day count beyond the
+    /// `i32` range, including days carried over from fractional months, so
+    /// `3000000000 days` comes back as `2147483647 days`.
+    pub fn new_strict(interval: &str) -> Result<Interval, ParseError> {
+        Interval::parse(interval, true)
+    }
+
+    /// Like `new`, but a bare number such as `90` or `1.5` is also accepted and read as
+    /// seconds, rounded to the nearest microsecond. If the input is neither, the error
+    /// from `new` is returned.
+    pub fn from_str_or_seconds(interval: &str) -> Result<Interval, ParseError> {
+        let err = match Interval::new(interval) {
+            Ok(parsed) => return Ok(parsed),
+            Err(err) => err,
         };
-        let serialized = serde_json::to_string(&interval).unwrap();
-        assert_eq!(serialized, r#"{"m":1,"d":2,"us":3}"#);
+        match interval.trim().parse::<f64>() {
+            Ok(seconds) if seconds.is_finite() && (seconds * 1_000_000.0).abs() <= i64::MAX as f64 => {
+                Ok(Interval::from_components(0, 0, (seconds * 1_000_000.0).round() as i64))
+            }
+            _ => Err(err),
+        }
     }
 
-    #[test]
-    fn test_interval_deserialize() {
-        let deserialized: Interval = serde_json::from_str(r#"{"m":1,"d":2,"us":3}"#).unwrap();
-        assert_eq!(deserialized.pg.months, 1);
-        assert_eq!(deserialized.pg.days, 2);
-        assert_eq!(deserialized.pg.microseconds, 3);
+    /// Parses the Postgres `postgres_verbose` output style, e.g. `@ 1 year 2 mons 3 mins`.
+    /// The leading `@` is optional, singular and abbreviated unit names are accepted, and
+    /// a trailing `ago` negates every field, so `@ 1 year ago` equals `-1 years`. The
+    /// unitless `@ 0` that Postgres writes for a zero interval is read as `Interval::ZERO`.
+    pub fn parse_postgres_verbose(interval: &str) -> Result<Interval, ParseError> {
+        let interval = interval.trim();
+        let interval = interval.strip_prefix('@').unwrap_or(interval);
+        let mut tokens = interval.split_whitespace().collect::<Vec<&str>>();
+        let is_ago = tokens.last() == Some(&"ago");
+        if is_ago {
+            tokens.pop();
+        }
+        if tokens == ["0"] {
+            return Ok(Interval::ZERO);
+        }
+        let parsed = Interval::new(&tokens.join(" "))?;
+        Ok(if is_ago { -parsed } else { parsed })
+    }
+
+    /// Parses an ISO 8601 duration such as `P1Y2M3DT4H5M6.5S`. `M` means months before
+    /// the `T` separator and minutes after it. The week form `P2W` is stored as days and
+    /// must stand alone, so `P1Y2W` is rejected. ISO 8601 allows a fractional week such
+    /// as `P1.5W`, but this crate only accepts a whole number of weeks.
+    pub fn from_iso8601(interval: &str) -> Result<Interval, ParseError> {
+        if !interval.starts_with('P') {
+            return Err(pg_interval::ParseError::from_invalid_interval("Invalid format must start with P.").into());
+        }
+        if let Some(weeks) = interval.strip_suffix('W') {
+            let days = weeks[1..].parse::<i32>().ok().and_then(|weeks| weeks.checked_mul(7)).ok_or_else(|| {
+                pg_interval::ParseError::from_invalid_interval("Week durations must be a whole number of weeks with no other components.")
+            })?;
+            return Ok(Interval::from_days(days));
+        }
+        Ok(Interval {
+            pg: pg_interval::Interval::from_iso(interval)?,
+        })
+    }
+
+    /// Formats the interval as an ISO 8601 duration such as `P1Y2M3DT4H5M6.5S`.
+    pub fn to_iso8601(&self) -> String {
+        if self.is_zero() {
+            return "PT0S".to_string();
+        }
+        let mut buf = String::from("P");
+        let years = self.pg.months / 12;
+        let months = self.pg.months % 12;
+        if years != 0 {
+            buf.push_str(&format!("{}Y", years));
+        }
+        if months != 0 {
+            buf.push_str(&format!("{}M", months));
+        }
+        if self.pg.days != 0 {
+            buf.push_str(&format!("{}D", self.pg.days));
+        }
+        if self.pg.microseconds != 0 {
+            let hours = self.pg.microseconds / 3_600_000_000;
+            let minutes = (self.pg.microseconds % 3_600_000_000) / 60_000_000;
+            let seconds = self.pg.microseconds % 60_000_000;
+            buf.push('T');
+            if hours != 0 {
+                buf.push_str(&format!("{}H", hours));
+            }
+            if minutes != 0 {
+                buf.push_str(&format!("{}M", minutes));
+            }
+            if seconds != 0 {
+                let sign = if seconds < 0 { "-" } else { "" };
+                let seconds = seconds.unsigned_abs();
+                let fraction = seconds % 1_000_000;
+                if fraction == 0 {
+                    buf.push_str(&format!("{}{}S", sign, seconds / 1_000_000));
+                } else {
+                    let fraction = format!("{:06}", fraction);
+                    buf.push_str(&format!("{}{}.{}S", sign, seconds / 1_000_000, fraction.trim_end_matches('0')));
+                }
+            }
+        }
+        buf
+    }
+
+    /// Formats the interval the way Postgres does with its default `IntervalStyle`, e.g.
+    /// `1 year 2 mons 3 days 04:05:06`, packing the time into an `HH:MM:SS[.ffffff]` clock.
+    pub fn to_postgres_string(&self) -> String {
+        let mut buf = String::new();
+        self.write_postgres(&mut buf).expect("writing to a String cannot fail");
+        buf
+    }
+
+    /// Writes the `to_postgres_string` form straight into `w`, so hot paths such as logging
+    /// can reuse one buffer instead of allocating a fresh `String` per interval.
+    pub fn write_postgres<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        self.write_postgres_fields(w, None)
+    }
+
+    /// Like `to_postgres_string`, but the clock is always present and carries exactly
+    /// `digits` fractional-second digits, e.g. `1 day 04:05:06.500` for three digits, so
+    /// intervals line up in report columns. The microseconds are first rounded to that
+    /// precision, with exact halves rounding away from zero. More than six digits are
+    /// treated as six.
+    pub fn format_with_precision(&self, digits: u8) -> String {
+        let digits = digits.min(6);
+        let mut buf = String::new();
+        self.round_microseconds(10i64.pow(6 - u32::from(digits)))
+            .write_postgres_fields(&mut buf, Some(u32::from(digits)))
+            .expect("writing to a String cannot fail");
+        buf
+    }
+
+    /// Renders the interval approximately in a single unit for human-facing text, e.g.
+    /// `2 days` for `1 day 20 hours`. The unit is the largest of years, months, days,
+    /// hours, minutes and seconds that the `total_microseconds` reaches, using 30-day
+    /// months and 12-month years. The count is rounded to the nearest whole unit with
+    /// halves rounding up, and a count that rounds up to the next unit is promoted to it,
+    /// so `23 hours 40 minutes` becomes `1 day`. Anything under half a second is
+    /// `0 seconds`, and negative intervals get a leading `-`.
+    pub fn humanize(&self) -> String {
+        const UNITS: [(i128, &str, &str); 6] = [
+            (12 * DAYS_PER_MONTH * MICROSECONDS_PER_DAY, "year", "years"),
+            (DAYS_PER_MONTH * MICROSECONDS_PER_DAY, "month", "months"),
+            (MICROSECONDS_PER_DAY, "day", "days"),
+            (3_600_000_000, "hour", "hours"),
+            (60_000_000, "minute", "minutes"),
+            (1_000_000, "second", "seconds"),
+        ];
+        let total = self.total_microseconds();
+        let magnitude = total.abs();
+        let mut index = UNITS.iter().position(|&(size, _, _)| magnitude >= size).unwrap_or(UNITS.len() - 1);
+        let mut count = (magnitude + UNITS[index].0 / 2) / UNITS[index].0;
+        if index > 0 && count * UNITS[index].0 >= UNITS[index - 1].0 {
+            index -= 1;
+            count = (magnitude + UNITS[index].0 / 2) / UNITS[index].0;
+        }
+        let sign = if total < 0 && count != 0 { "-" } else { "" };
+        let (_, singular, plural) = UNITS[index];
+        format!("{}{} {}", sign, count, if count == 1 { singular } else { plural })
+    }
+
+    /// Splits the interval into the units `Display` writes. Months are divided into years
+    /// and months, and the microseconds into hours, minutes, seconds, milliseconds and
+    /// microseconds; days are kept as they are. Each part carries the sign of the field it
+    /// came from.
+    pub fn components(&self) -> IntervalComponents {
+        // Split the magnitude and reattach the sign afterwards so every part is computed
+        // from the same non-negative value rather than relying on `%` keeping the sign.
+        let sign = self.pg.microseconds.signum();
+        let microseconds = self.pg.microseconds.unsigned_abs();
+        IntervalComponents {
+            years: self.pg.months / 12,
+            months: self.pg.months % 12,
+            days: self.pg.days,
+            hours: sign * (microseconds / 3_600_000_000) as i64,
+            minutes: sign * (microseconds % 3_600_000_000 / 60_000_000) as i64,
+            seconds: sign * (microseconds % 60_000_000 / 1_000_000) as i64,
+            milliseconds: sign * (microseconds % 1_000_000 / 1_000) as i64,
+            microseconds: sign * (microseconds % 1_000) as i64,
+        }
+    }
+
+    /// Writes the same unit-by-unit breakdown as `Display`, but with the unit words taken
+    /// from `names`, e.g. `1 Jahr 2 Monate 3 Tage`. Only non-zero units are written and a
+    /// zero interval is rendered as `0` followed by `names.seconds`.
+    pub fn format_localized(&self, names: &UnitNames) -> String {
+        let components = self.components();
+        let units = [
+            (i64::from(components.years), names.year, names.years),
+            (i64::from(components.months), names.month, names.months),
+            (i64::from(components.days), names.day, names.days),
+            (components.hours, names.hour, names.hours),
+            (components.minutes, names.minute, names.minutes),
+            (components.seconds, names.second, names.seconds),
+            (components.milliseconds, names.millisecond, names.milliseconds),
+            (components.microseconds, names.microsecond, names.microseconds),
+        ];
+        let mut buf = String::new();
+        for (value, singular, plural) in units {
+            if value == 0 {
+                continue;
+            }
+            if !buf.is_empty() {
+                buf.push(' ');
+            }
+            buf.push_str(&format!("{} {}", value, if value == 1 { singular } else { plural }));
+        }
+        if buf.is_empty() {
+            buf.push_str(&format!("0 {}", names.seconds));
+        }
+        buf
+    }
+
+    fn write_postgres_fields<W: std::fmt::Write>(&self, w: &mut W, digits: Option<u32>) -> std::fmt::Result {
+        let mut wrote_any = false;
+        let mut is_before = false;
+        for (value, unit) in [(self.pg.months / 12, "year"), (self.pg.months % 12, "mon"), (self.pg.days, "day")] {
+            if value == 0 {
+                continue;
+            }
+            if wrote_any {
+                w.write_char(' ')?;
+            }
+            let sign = if is_before && value > 0 { "+" } else { "" };
+            let plural = if value != 1 { "s" } else { "" };
+            write!(w, "{}{} {}{}", sign, value, unit, plural)?;
+            wrote_any = true;
+            is_before = value < 0;
+        }
+        if !wrote_any || self.pg.microseconds != 0 || digits.is_some() {
+            if wrote_any {
+                w.write_char(' ')?;
+            }
+            let sign = if self.pg.microseconds < 0 {
+                "-"
+            } else if is_before {
+                "+"
+            } else {
+                ""
+            };
+            let microseconds = self.pg.microseconds.unsigned_abs();
+            let hours = microseconds / 3_600_000_000;
+            let minutes = microseconds % 3_600_000_000 / 60_000_000;
+            let seconds = microseconds % 60_000_000 / 1_000_000;
+            let mut fraction = microseconds % 1_000_000;
+            write!(w, "{}{:02}:{:02}:{:02}", sign, hours, minutes, seconds)?;
+            let width = match digits {
+                Some(digits) => {
+                    fraction /= 10u64.pow(6 - digits);
+                    digits
+                }
+                None if fraction == 0 => 0,
+                None => {
+                    let mut width = 6;
+                    while fraction % 10 == 0 {
+                        fraction /= 10;
+                        width -= 1;
+                    }
+                    width
+                }
+            };
+            if width > 0 {
+                write!(w, ".{:0width$}", fraction, width = width as usize)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Decodes an interval delivered in the Postgres text format rather than the binary
+    /// layout handled by `FromSql`.
+    pub fn from_sql_text(raw: &[u8]) -> Result<Interval, Box<dyn Error + Sync + Send>> {
+        Ok(Interval::new(std::str::from_utf8(raw)?)?)
+    }
+
+    pub fn inner(&self) -> &pg_interval::Interval {
+        &self.pg
+    }
+
+    pub fn into_inner(self) -> pg_interval::Interval {
+        self.pg
+    }
+
+    /// Splits the interval into its calendar part, holding the months and days, and its
+    /// clock part, holding the microseconds, e.g. for showing `1 mon 2 days` and
+    /// `03:04:05` in separate columns. Adding the two halves gives back the original.
+    pub fn split_time_of_day(&self) -> (Interval, Interval) {
+        (
+            Interval::from_components(self.pg.months, self.pg.days, 0),
+            Interval::from_components(0, 0, self.pg.microseconds),
+        )
+    }
+
+    /// Zeros every unit outside `fields`, so `DAY TO SECOND` drops the months and `YEAR TO
+    /// MONTH` drops the days and microseconds. Units below the smallest one in the set are
+    /// truncated toward zero, like Postgres does when storing into a restricted column;
+    /// unlike Postgres, units above the largest one are dropped too, e.g. `MINUTE TO
+    /// SECOND` keeps only the minutes and seconds within the hour.
+    pub fn restrict_to(&self, fields: IntervalFields) -> Interval {
+        let (largest, smallest) = fields.range();
+        let includes = |rank: u8| largest <= rank && rank <= smallest;
+        let years = if includes(0) { self.pg.months / 12 * 12 } else { 0 };
+        let months = if includes(1) { self.pg.months % 12 } else { 0 };
+        let days = if includes(2) { self.pg.days } else { 0 };
+        let mut microseconds = match largest {
+            0..=3 => self.pg.microseconds,
+            4 => self.pg.microseconds % 3_600_000_000,
+            _ => self.pg.microseconds % 60_000_000,
+        };
+        microseconds = match smallest {
+            0..=2 => 0,
+            3 => microseconds - microseconds % 3_600_000_000,
+            4 => microseconds - microseconds % 60_000_000,
+            _ => microseconds,
+        };
+        Interval::from_components(years + months, days, microseconds)
+    }
+
+    /// Mirrors Postgres' `justify_interval()`: whole 24-hour periods are moved into days
+    /// and whole 30-day periods into months, then the fields are adjusted so they all
+    /// share the same sign. The carries are summed without overflow, and `None` is returned
+    /// only if the resulting months do not fit an `i32`.
+    pub fn justify_interval(&self) -> Option<Interval> {
+        let mut months = i64::from(self.pg.months);
+        let mut days = i64::from(self.pg.days);
+        let mut microseconds = self.pg.microseconds;
+        if (days > 0 && microseconds > 0) || (days < 0 && microseconds < 0) {
+            months += days / 30;
+            days %= 30;
+        }
+        days += microseconds / 86_400_000_000;
+        microseconds %= 86_400_000_000;
+        months += days / 30;
+        days %= 30;
+        if months > 0 && (days < 0 || (days == 0 && microseconds < 0)) {
+            days += 30;
+            months -= 1;
+        } else if months < 0 && (days > 0 || (days == 0 && microseconds > 0)) {
+            days -= 30;
+            months += 1;
+        }
+        if days > 0 && microseconds < 0 {
+            microseconds += 86_400_000_000;
+            days -= 1;
+        } else if days < 0 && microseconds > 0 {
+            microseconds -= 86_400_000_000;
+            days += 1;
+        }
+        // At most 30 days remain once the rest are carried into months.
+        Some(Interval::from_components(i32::try_from(months).ok()?, days as i32, microseconds))
+    }
+
+    /// Mirrors Postgres' `justify_hours()`: whole 24-hour periods are moved into days.
+    /// Returns `None` if the days no longer fit an `i32`, where Postgres reports
+    /// `interval out of range`.
+    pub fn justify_hours(&self) -> Option<Interval> {
+        let mut days = i64::from(self.pg.days) + self.pg.microseconds / 86_400_000_000;
+        let mut microseconds = self.pg.microseconds % 86_400_000_000;
+        if days > 0 && microseconds < 0 {
+            microseconds += 86_400_000_000;
+            days -= 1;
+        } else if days < 0 && microseconds > 0 {
+            microseconds -= 86_400_000_000;
+            days += 1;
+        }
+        Some(Interval::from_components(self.pg.months, i32::try_from(days).ok()?, microseconds))
+    }
+
+    /// Mirrors Postgres' `justify_days()`: whole 30-day periods are moved into months.
+    /// Returns `None` if the months no longer fit an `i32`, where Postgres reports
+    /// `interval out of range`.
+    pub fn justify_days(&self) -> Option<Interval> {
+        let mut months = self.pg.months.checked_add(self.pg.days / 30)?;
+        let mut days = self.pg.days % 30;
+        if months > 0 && days < 0 {
+            days += 30;
+            months -= 1;
+        } else if months < 0 && days > 0 {
+            days -= 30;
+            months += 1;
+        }
+        Some(Interval::from_components(months, days, self.pg.microseconds))
+    }
+
+    /// Collapses the interval into its `total_microseconds`, treating every month as 30
+    /// days and every day as 24 hours, and spreads that total back over months, days and
+    /// microseconds so that every field shares its sign: `1 mon -5 days` becomes
+    /// `25 days`. Unlike `justify_interval`, the existing months are folded in too, so the
+    /// result depends only on the total. A month count that does not fit an `i32` wraps.
+    pub fn normalize_sign(&self) -> Interval {
+        Interval::from_total_microseconds(self.total_microseconds())
+    }
+
+    fn from_total_microseconds(total: i128) -> Interval {
+        let months = total / (DAYS_PER_MONTH * MICROSECONDS_PER_DAY);
+        let days = total % (DAYS_PER_MONTH * MICROSECONDS_PER_DAY) / MICROSECONDS_PER_DAY;
+        let microseconds = total % MICROSECONDS_PER_DAY;
+        Interval::from_components(months as i32, days as i32, microseconds as i64)
+    }
+
+    /// Interpolates `t` of the way from `a` to `b`, with `t` clamped to `0.0..=1.0`; use
+    /// `lerp_unclamped` to extrapolate. Both ends are compared by their
+    /// `total_microseconds` (30-day months, 24-hour days), and the interpolated total is
+    /// rounded to the nearest microsecond and spread back over months, days and
+    /// microseconds like `normalize_sign`, so `lerp(a, b, 0.0)` equals `a.normalize_sign()`
+    /// rather than `a` itself. A NaN `t` yields `a.normalize_sign()`.
+    pub fn lerp(a: Interval, b: Interval, t: f64) -> Interval {
+        Interval::lerp_unclamped(a, b, if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) })
+    }
+
+    /// Like `lerp`, but a `t` outside `0.0..=1.0` extrapolates beyond `a` or `b`. A total
+    /// that does not fit the interval's fields wraps like `normalize_sign`; an infinite or
+    /// huge `t` first saturates the total at the `i128` bounds.
+    pub fn lerp_unclamped(a: Interval, b: Interval, t: f64) -> Interval {
+        let start = a.total_microseconds();
+        let offset = ((b.total_microseconds() - start) as f64 * t).round() as i128;
+        Interval::from_total_microseconds(start.saturating_add(offset))
+    }
+
+    /// Takes the absolute value of each field independently. `i32::MIN` and `i64::MIN`
+    /// saturate to `i32::MAX` and `i64::MAX` so the result is never negative.
+    pub fn abs(&self) -> Interval {
+        Interval::from_components(
+            self.pg.months.saturating_abs(),
+            self.pg.days.saturating_abs(),
+            self.pg.microseconds.saturating_abs(),
+        )
+    }
+
+    /// Rounds the microseconds to the nearest multiple of `unit`, leaving months and
+    /// days untouched. Exact halves round away from zero, so `2.5 seconds` becomes
+    /// `3 seconds` and `-2.5 seconds` becomes `-3 seconds`. Only the microsecond field is
+    /// rounded, even for `RoundUnit::Hour` and `RoundUnit::Day`: a result of 24 hours
+    /// stays in the microseconds rather than being carried into days, and nothing is
+    /// ever carried into months. Use `justify_hours` afterwards to move it. A result
+    /// beyond the `i64` range saturates.
+    pub fn round_to(&self, unit: RoundUnit) -> Interval {
+        self.round_microseconds(unit.microseconds())
+    }
+
+    fn round_microseconds(&self, step: i64) -> Interval {
+        let remainder = self.pg.microseconds % step;
+        let mut microseconds = self.pg.microseconds - remainder;
+        if remainder >= (step + 1) / 2 {
+            microseconds = microseconds.saturating_add(step);
+        } else if remainder <= -(step + 1) / 2 {
+            microseconds = microseconds.saturating_sub(step);
+        }
+        Interval::from_components(self.pg.months, self.pg.days, microseconds)
+    }
+
+    /// Shorthand for `round_to(RoundUnit::Second)`.
+    pub fn round_to_seconds(&self) -> Interval {
+        self.round_to(RoundUnit::Second)
+    }
+
+    /// Shorthand for `round_to(RoundUnit::Minute)`.
+    pub fn round_to_minutes(&self) -> Interval {
+        self.round_to(RoundUnit::Minute)
+    }
+
+    /// Drops the sub-second part of the microseconds, truncating toward zero, and leaves
+    /// months and days untouched.
+    pub fn truncate_to_seconds(&self) -> Interval {
+        Interval::from_components(self.pg.months, self.pg.days, self.pg.microseconds - self.pg.microseconds % 1_000_000)
+    }
+
+    /// Adds field-wise, returning `None` if any field overflows.
+    pub fn checked_add(self, rhs: Interval) -> Option<Interval> {
+        Some(Interval::from_components(
+            self.pg.months.checked_add(rhs.pg.months)?,
+            self.pg.days.checked_add(rhs.pg.days)?,
+            self.pg.microseconds.checked_add(rhs.pg.microseconds)?,
+        ))
+    }
+
+    /// Subtracts field-wise, returning `None` if any field overflows.
+    pub fn checked_sub(self, rhs: Interval) -> Option<Interval> {
+        Some(Interval::from_components(
+            self.pg.months.checked_sub(rhs.pg.months)?,
+            self.pg.days.checked_sub(rhs.pg.days)?,
+            self.pg.microseconds.checked_sub(rhs.pg.microseconds)?,
+        ))
+    }
+
+    /// Negates field-wise, returning `None` if any field is at its minimum, where plain
+    /// `-` wraps.
+    pub fn checked_neg(self) -> Option<Interval> {
+        Some(Interval::from_components(
+            self.pg.months.checked_neg()?,
+            self.pg.days.checked_neg()?,
+            self.pg.microseconds.checked_neg()?,
+        ))
+    }
+
+    /// Adds field-wise, with each field saturating at its own bounds independently of
+    /// the others.
+    pub fn saturating_add(self, rhs: Interval) -> Interval {
+        Interval::from_components(
+            self.pg.months.saturating_add(rhs.pg.months),
+            self.pg.days.saturating_add(rhs.pg.days),
+            self.pg.microseconds.saturating_add(rhs.pg.microseconds),
+        )
+    }
+
+    /// Subtracts field-wise, with each field saturating at its own bounds independently
+    /// of the others.
+    pub fn saturating_sub(self, rhs: Interval) -> Interval {
+        Interval::from_components(
+            self.pg.months.saturating_sub(rhs.pg.months),
+            self.pg.days.saturating_sub(rhs.pg.days),
+            self.pg.microseconds.saturating_sub(rhs.pg.microseconds),
+        )
+    }
+
+    /// Adds field-wise with each field wrapping on overflow, and also returns whether any
+    /// of them overflowed.
+    pub fn overflowing_add(self, rhs: Interval) -> (Interval, bool) {
+        let (months, months_overflowed) = self.pg.months.overflowing_add(rhs.pg.months);
+        let (days, days_overflowed) = self.pg.days.overflowing_add(rhs.pg.days);
+        let (microseconds, microseconds_overflowed) = self.pg.microseconds.overflowing_add(rhs.pg.microseconds);
+        (Interval::from_components(months, days, microseconds), months_overflowed || days_overflowed || microseconds_overflowed)
+    }
+
+    /// Multiplies each field by `rhs`, returning `None` if any of them overflows.
+    pub fn checked_mul(self, rhs: i32) -> Option<Interval> {
+        Some(Interval::from_components(
+            self.pg.months.checked_mul(rhs)?,
+            self.pg.days.checked_mul(rhs)?,
+            self.pg.microseconds.checked_mul(i64::from(rhs))?,
+        ))
+    }
+
+    /// Restricts the interval to `min..=max` using the `Ord` impl, so bounds are compared
+    /// by their total duration: clamping `45 days` to at most `1 mons` yields `1 mons`.
+    /// Panics if `min` is greater than `max`.
+    pub fn clamp(self, min: Interval, max: Interval) -> Interval {
+        Ord::clamp(self, min, max)
+    }
+
+    /// Scales the interval exactly like `Mul<f64>`, but fails for a NaN or infinite
+    /// `factor` and when a scaled field does not fit its `i32`/`i64` field instead of
+    /// saturating.
+    pub fn checked_mul_f64(&self, factor: f64) -> Result<Interval, ScaleError> {
+        if !factor.is_finite() {
+            return Err(ScaleError::new("cannot scale an interval by a non-finite factor"));
+        }
+        let (months, days, microseconds) = self.scale_f64(factor);
+        let whole_days = days.iter().sum::<f64>();
+        let microseconds = microseconds.round_ties_even();
+        let i32_range = f64::from(i32::MIN)..=f64::from(i32::MAX);
+        let i64_range = -9_223_372_036_854_775_808.0..9_223_372_036_854_775_808.0;
+        if !i32_range.contains(&months) || !i32_range.contains(&whole_days) || !i64_range.contains(&microseconds) {
+            return Err(ScaleError::new("scaled interval is out of range"));
+        }
+        Ok(Interval::from_components(months as i32, whole_days as i32, microseconds as i64))
+    }
+
+    /// Scales each field by `factor` for `Mul<f64>` and `checked_mul_f64`, leaving the
+    /// range handling to them. Returns the whole months, the whole days as three parts
+    /// (the scaled days, the days spilled from the months and the day carried out of the
+    /// combined remainder) and the unrounded microseconds.
+    fn scale_f64(&self, factor: f64) -> (f64, [f64; 3], f64) {
+        let months = f64::from(self.pg.months) * factor;
+        let month_remainder_days = months.fract() * 30.0;
+        let days = f64::from(self.pg.days) * factor;
+        let day_remainder = days.fract() + month_remainder_days.fract();
+        let microseconds = self.pg.microseconds as f64 * factor + day_remainder.fract() * 86_400_000_000.0;
+        (months.trunc(), [days.trunc(), month_remainder_days.trunc(), day_remainder.trunc()], microseconds)
+    }
+
+    /// Collapses the interval into a single microsecond count, treating every month as
+    /// 30 days and every day as 24 hours like Postgres does when comparing intervals.
+    pub fn total_microseconds(&self) -> i128 {
+        i128::from(self.pg.months) * DAYS_PER_MONTH * MICROSECONDS_PER_DAY
+            + i128::from(self.pg.days) * MICROSECONDS_PER_DAY
+            + i128::from(self.pg.microseconds)
+    }
+
+    /// Whether the `total_microseconds` of the two intervals differ by at most
+    /// `tolerance`, counting every month as 30 days and every day as 24 hours. Handy for
+    /// comparing values that went through lossy conversions; `tolerance` is truncated to
+    /// whole microseconds.
+    pub fn approx_eq(&self, other: &Interval, tolerance: Duration) -> bool {
+        let difference = (self.total_microseconds() - other.total_microseconds()).unsigned_abs();
+        difference <= tolerance.as_micros()
+    }
+
+    /// Whether the interval points backwards in time. A mixed-sign interval such as
+    /// `1 mons -31 days` is judged by its `total_microseconds`, not by any single field.
+    pub fn is_negative(&self) -> bool {
+        self.total_microseconds() < 0
+    }
+
+    /// The direction of the interval as `-1`, `0` or `1`, judged by its
+    /// `total_microseconds` like `is_negative`.
+    pub fn signum(&self) -> i32 {
+        self.total_microseconds().signum() as i32
+    }
+
+    /// The number of whole minutes in the `total_microseconds`. The remainder is
+    /// truncated toward zero, so `-90 seconds` is `-1` minute.
+    pub fn as_whole_minutes(&self) -> i128 {
+        self.total_microseconds() / 60_000_000
+    }
+
+    /// The number of whole hours in the `total_microseconds`, truncated toward zero.
+    pub fn as_whole_hours(&self) -> i128 {
+        self.total_microseconds() / 3_600_000_000
+    }
+
+    /// The number of whole 24-hour days in the `total_microseconds`, truncated toward
+    /// zero. A month counts as 30 days.
+    pub fn as_whole_days(&self) -> i128 {
+        self.total_microseconds() / MICROSECONDS_PER_DAY
+    }
+
+    /// The `total_microseconds` as seconds. Beyond 2^53 microseconds (about 285 years)
+    /// an `f64` can no longer represent every microsecond exactly.
+    pub fn as_seconds_f64(&self) -> f64 {
+        self.total_microseconds() as f64 / 1_000_000.0
+    }
+
+    /// Matches Postgres' `EXTRACT(EPOCH FROM interval)`. Like Postgres, whole years count
+    /// as 365.25 days while the remaining months count as 30 days and days as 24 hours,
+    /// so `1 year` is 31557600 seconds whereas `as_seconds_f64` gives 31104000.
+    pub fn epoch_seconds(&self) -> f64 {
+        let years = f64::from(self.pg.months / 12) * 365.25 * 86_400.0;
+        let months = f64::from(self.pg.months % 12) * 30.0 * 86_400.0;
+        let days = f64::from(self.pg.days) * 86_400.0;
+        years + months + days + self.pg.microseconds as f64 / 1_000_000.0
+    }
+
+    /// Converts the `total_microseconds` to a `Duration` without failing, unlike
+    /// `Duration::try_from`: a negative interval becomes `Duration::ZERO` and anything
+    /// beyond `u64::MAX` microseconds is clamped to that. Handy for "sleep this long"
+    /// code where an out-of-range value should simply become zero or a maximum.
+    pub fn as_std_duration_lossy(&self) -> Duration {
+        let microseconds = self.total_microseconds().clamp(0, i128::from(u64::MAX));
+        Duration::from_micros(microseconds as u64)
+    }
+
+    pub fn months(&self) -> i32 {
+        self.pg.months
+    }
+
+    pub fn days(&self) -> i32 {
+        self.pg.days
+    }
+
+    pub fn microseconds(&self) -> i64 {
+        self.pg.microseconds
+    }
+
+    /// Serializes the interval as the `{"m":..,"d":..,"us":..}` JSON object, or the ISO 8601
+    /// string with the `serde-iso` feature, ready to be embedded in a JSONB column.
+    pub fn to_jsonb_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("serializing an interval to JSON cannot fail")
+    }
+
+    /// Reverses `to_jsonb_bytes`, also accepting anything else the `Deserialize` impl
+    /// accepts, such as a Postgres interval string.
+    pub fn from_jsonb_bytes(raw: &[u8]) -> Result<Interval, serde_json::Error> {
+        serde_json::from_slice(raw)
+    }
+
+    pub fn bytes(&self) -> Vec<u8> {
+        let mut buf = vec![0u8; 16];
+        buf[0..8].copy_from_slice(&self.pg.microseconds.to_be_bytes());
+        buf[8..12].copy_from_slice(&self.pg.days.to_be_bytes());
+        buf[12..16].copy_from_slice(&self.pg.months.to_be_bytes());
+        buf
+    }
+}
+
+/// Builds an `Interval` unit by unit. Calling a method more than once adds to the
+/// amount already given for that unit rather than replacing it. Years are folded into
+/// months and every time unit into microseconds; values that do not fit the backing
+/// `i32`/`i64` fields wrap around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IntervalBuilder {
+    years: i128,
+    months: i128,
+    days: i128,
+    hours: i128,
+    minutes: i128,
+    seconds: i128,
+    milliseconds: i128,
+    microseconds: i128,
+}
+
+impl IntervalBuilder {
+    pub fn new() -> IntervalBuilder {
+        IntervalBuilder::default()
+    }
+
+    pub fn years(mut self, years: i32) -> IntervalBuilder {
+        self.years += i128::from(years);
+        self
+    }
+
+    pub fn months(mut self, months: i32) -> IntervalBuilder {
+        self.months += i128::from(months);
+        self
+    }
+
+    pub fn days(mut self, days: i32) -> IntervalBuilder {
+        self.days += i128::from(days);
+        self
+    }
+
+    pub fn hours(mut self, hours: i64) -> IntervalBuilder {
+        self.hours += i128::from(hours);
+        self
+    }
+
+    pub fn minutes(mut self, minutes: i64) -> IntervalBuilder {
+        self.minutes += i128::from(minutes);
+        self
+    }
+
+    pub fn seconds(mut self, seconds: i64) -> IntervalBuilder {
+        self.seconds += i128::from(seconds);
+        self
+    }
+
+    pub fn milliseconds(mut self, milliseconds: i64) -> IntervalBuilder {
+        self.milliseconds += i128::from(milliseconds);
+        self
+    }
+
+    pub fn microseconds(mut self, microseconds: i64) -> IntervalBuilder {
+        self.microseconds += i128::from(microseconds);
+        self
+    }
+
+    pub fn build(&self) -> Interval {
+        Interval::from_components(
+            (self.years * 12 + self.months) as i32,
+            self.days as i32,
+            (self.hours * 3_600_000_000
+                + self.minutes * 60_000_000
+                + self.seconds * 1_000_000
+                + self.milliseconds * 1_000
+                + self.microseconds) as i64,
+        )
+    }
+
+    /// Like `build`, but fails instead of wrapping when years and months do not fit the
+    /// `i32` months field, the days do not fit `i32`, or the time units together do not
+    /// fit the `i64` microseconds field.
+    pub fn try_build(&self) -> Result<Interval, BuildError> {
+        let months = IntervalBuilder::sum_into(&[
+            (BuildErrorUnit::Years, self.years * 12),
+            (BuildErrorUnit::Months, self.months),
+        ])?;
+        let days = IntervalBuilder::sum_into(&[(BuildErrorUnit::Days, self.days)])?;
+        let microseconds = IntervalBuilder::sum_into(&[
+            (BuildErrorUnit::Hours, self.hours * 3_600_000_000),
+            (BuildErrorUnit::Minutes, self.minutes * 60_000_000),
+            (BuildErrorUnit::Seconds, self.seconds * 1_000_000),
+            (BuildErrorUnit::Milliseconds, self.milliseconds * 1_000),
+            (BuildErrorUnit::Microseconds, self.microseconds),
+        ])?;
+        Ok(Interval::from_components(months, days, microseconds))
+    }
+
+    fn sum_into<T: TryFrom<i128>>(parts: &[(BuildErrorUnit, i128)]) -> Result<T, BuildError> {
+        let total = parts.iter().map(|(_, part)| part).sum::<i128>();
+        T::try_from(total).map_err(|_| {
+            let (unit, _) = parts.iter().max_by_key(|(_, part)| part * total.signum()).expect("parts are never empty");
+            BuildError { unit: *unit }
+        })
+    }
+}
+
+impl Default for Interval {
+    fn default() -> Interval {
+        Interval::zero()
+    }
+}
+
+impl From<pg_interval::Interval> for Interval {
+    fn from(pg: pg_interval::Interval) -> Interval {
+        Interval {
+            pg
+        }
+    }
+}
+
+impl From<Interval> for pg_interval::Interval {
+    fn from(interval: Interval) -> pg_interval::Interval {
+        interval.pg
+    }
+}
+
+/// Stores the whole value in the microseconds field, failing if it does not fit an `i64`.
+impl TryFrom<i128> for Interval {
+    type Error = ConversionError;
+
+    fn try_from(microseconds: i128) -> Result<Interval, ConversionError> {
+        i64::try_from(microseconds)
+            .map(|microseconds| Interval::from_components(0, 0, microseconds))
+            .map_err(|_| ConversionError::new("microseconds are out of range for an interval"))
+    }
+}
+
+/// Stores the whole duration in the microseconds field, truncating anything below a
+/// microsecond. Durations longer than `i64::MAX` microseconds saturate to that value.
+impl From<Duration> for Interval {
+    fn from(duration: Duration) -> Interval {
+        let microseconds = i64::try_from(duration.as_micros()).unwrap_or(i64::MAX);
+        Interval::from_components(0, 0, microseconds)
+    }
+}
+
+/// Converts to a `Duration` by treating every month as 30 days and every day as
+/// 24 hours, the same canonicalization Postgres uses when comparing intervals. This
+/// loses calendar accuracy: `1 mons` becomes exactly 30 days regardless of which
+/// month it would be applied to. Negative intervals cannot be represented and fail.
+impl TryFrom<Interval> for Duration {
+    type Error = ConversionError;
+
+    fn try_from(interval: Interval) -> Result<Duration, ConversionError> {
+        let microseconds = interval.total_microseconds();
+        if microseconds < 0 {
+            return Err(ConversionError::new("cannot convert a negative interval to a std::time::Duration"));
+        }
+        let seconds = (microseconds / 1_000_000) as u64;
+        let nanoseconds = (microseconds % 1_000_000) as u32 * 1_000;
+        Ok(Duration::new(seconds, nanoseconds))
+    }
+}
+
+/// Stores the whole duration in the microseconds field, truncating anything below a
+/// microsecond towards zero. Durations beyond `i64` microseconds saturate.
+#[cfg(feature = "chrono")]
+impl From<chrono::Duration> for Interval {
+    fn from(duration: chrono::Duration) -> Interval {
+        let microseconds = duration.num_microseconds().unwrap_or(if duration < chrono::Duration::zero() {
+            i64::MIN
+        } else {
+            i64::MAX
+        });
+        Interval::from_components(0, 0, microseconds)
+    }
+}
+
+/// Converts to a `chrono::Duration` using the same 30-day month and 24-hour day
+/// canonicalization as the `std::time::Duration` conversion.
+#[cfg(feature = "chrono")]
+impl TryFrom<Interval> for chrono::Duration {
+    type Error = ConversionError;
+
+    fn try_from(interval: Interval) -> Result<chrono::Duration, ConversionError> {
+        i64::try_from(interval.total_microseconds())
+            .map(chrono::Duration::microseconds)
+            .map_err(|_| ConversionError::new("interval is out of range for a chrono::Duration"))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Interval {
+    /// Adds the interval to `dt` the way Postgres evaluates `timestamp + interval`: the
+    /// months are applied as calendar months first, clamping to the last day of a shorter
+    /// month (`2024-01-31 + 1 mons` is `2024-02-29`), then the days, then the
+    /// microseconds. Panics if the result is out of range for a `NaiveDateTime`.
+    pub fn add_to_datetime(&self, dt: chrono::NaiveDateTime) -> chrono::NaiveDateTime {
+        Interval::add_months_to_datetime(dt, Some(self.pg.months))
+            .and_then(|dt| dt.checked_add_signed(chrono::Duration::days(i64::from(self.pg.days))))
+            .and_then(|dt| dt.checked_add_signed(chrono::Duration::microseconds(self.pg.microseconds)))
+            .expect("`NaiveDateTime + Interval` overflowed")
+    }
+
+    /// Subtracts the interval from `dt` the way Postgres evaluates `timestamp - interval`:
+    /// calendar months first, clamping to the last day of a shorter month
+    /// (`2024-03-31 - 1 mons` is `2024-02-29`), then the days, then the microseconds.
+    /// Panics if the result is out of range for a `NaiveDateTime`.
+    pub fn subtract_from_datetime(&self, dt: chrono::NaiveDateTime) -> chrono::NaiveDateTime {
+        Interval::add_months_to_datetime(dt, self.pg.months.checked_neg())
+            .and_then(|dt| dt.checked_sub_signed(chrono::Duration::days(i64::from(self.pg.days))))
+            .and_then(|dt| dt.checked_sub_signed(chrono::Duration::microseconds(self.pg.microseconds)))
+            .expect("`NaiveDateTime - Interval` overflowed")
+    }
+
+    /// Moves the date `year-month-day` by `add` calendar months, clamping the day to the
+    /// last day of the target month, e.g. `(2024, 1, 31)` plus one month is
+    /// `(2024, 2, 29)`. This is the month step of `add_to_datetime`. `month` is 1-based.
+    /// Returns `None` if `month` is not between 1 and 12 or the year leaves the `i32`
+    /// range.
+    pub fn add_calendar_months(year: i32, month: u32, day: u32, add: i32) -> Option<(i32, u32, u32)> {
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+        let months = i64::from(year) * 12 + i64::from(month - 1) + i64::from(add);
+        let year = i32::try_from(months.div_euclid(12)).ok()?;
+        let month = months.rem_euclid(12) as u32 + 1;
+        let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days_in_month = match month {
+            2 if is_leap_year => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        };
+        Some((year, month, day.min(days_in_month)))
+    }
+
+    fn add_months_to_datetime(dt: chrono::NaiveDateTime, months: Option<i32>) -> Option<chrono::NaiveDateTime> {
+        use chrono::Datelike;
+        let (year, month, day) = Interval::add_calendar_months(dt.year(), dt.month(), dt.day(), months?)?;
+        Some(chrono::NaiveDate::from_ymd_opt(year, month, day)?.and_time(dt.time()))
+    }
+}
+
+/// Stores the whole duration in the microseconds field, truncating anything below a
+/// microsecond towards zero. Durations beyond `i64` microseconds saturate.
+#[cfg(feature = "time")]
+impl From<time::Duration> for Interval {
+    fn from(duration: time::Duration) -> Interval {
+        let microseconds = duration.whole_microseconds().clamp(i128::from(i64::MIN), i128::from(i64::MAX));
+        Interval::from_components(0, 0, microseconds as i64)
+    }
+}
+
+/// Converts to a `time::Duration` using the same 30-day month and 24-hour day
+/// canonicalization as the `std::time::Duration` conversion.
+#[cfg(feature = "time")]
+impl TryFrom<Interval> for time::Duration {
+    type Error = ConversionError;
+
+    fn try_from(interval: Interval) -> Result<time::Duration, ConversionError> {
+        let microseconds = interval.total_microseconds();
+        let seconds = i64::try_from(microseconds / 1_000_000)
+            .map_err(|_| ConversionError::new("interval is out of range for a time::Duration"))?;
+        Ok(time::Duration::new(seconds, (microseconds % 1_000_000) as i32 * 1_000))
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Interval::new(s)
+    }
+}
+
+impl TryFrom<&str> for Interval {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Interval, ParseError> {
+        Interval::new(s)
+    }
+}
+
+impl TryFrom<String> for Interval {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Interval, ParseError> {
+        Interval::new(&s)
+    }
+}
+
+/// The unit-by-unit breakdown returned by `Interval::components`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IntervalComponents {
+    pub years: i32,
+    pub months: i32,
+    pub days: i32,
+    pub hours: i64,
+    pub minutes: i64,
+    pub seconds: i64,
+    pub milliseconds: i64,
+    pub microseconds: i64,
+}
+
+/// The singular and plural unit words `Interval::format_localized` writes, letting
+/// callers render intervals in other languages. `UnitNames::default()` holds the English
+/// words `Display` uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitNames<'a> {
+    pub year: &'a str,
+    pub years: &'a str,
+    pub month: &'a str,
+    pub months: &'a str,
+    pub day: &'a str,
+    pub days: &'a str,
+    pub hour: &'a str,
+    pub hours: &'a str,
+    pub minute: &'a str,
+    pub minutes: &'a str,
+    pub second: &'a str,
+    pub seconds: &'a str,
+    pub millisecond: &'a str,
+    pub milliseconds: &'a str,
+    pub microsecond: &'a str,
+    pub microseconds: &'a str,
+}
+
+impl Default for UnitNames<'static> {
+    fn default() -> UnitNames<'static> {
+        UnitNames {
+            year: "year",
+            years: "years",
+            month: "mon",
+            months: "mons",
+            day: "day",
+            days: "days",
+            hour: "hour",
+            hours: "hours",
+            minute: "minute",
+            minutes: "minutes",
+            second: "second",
+            seconds: "seconds",
+            millisecond: "millisecond",
+            milliseconds: "milliseconds",
+            microsecond: "microsecond",
+            microseconds: "microseconds",
+        }
+    }
+}
+
+/// Shows the raw fields alongside the `Display` form, e.g.
+/// `Interval { months: 1, days: 2, microseconds: 3000000, display: "1 mon 2 days 3 seconds" }`.
+impl Debug for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Interval")
+            .field("months", &self.pg.months)
+            .field("days", &self.pg.days)
+            .field("microseconds", &self.pg.microseconds)
+            .field("display", &self.to_string())
+            .finish()
+    }
+}
+
+/// Spells out every unit, e.g. `1 year 2 mons 3 days 4 hours 5 minutes 6 seconds`. The
+/// alternate form `{:#}` writes `to_postgres_string` instead, e.g.
+/// `1 year 2 mons 3 days 04:05:06`.
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            self.write_postgres(f)
+        } else {
+            f.write_str(&self.format_localized(&UnitNames::default()))
+        }
+    }
+}
+
+/// Orders intervals the way Postgres does, by their total length in microseconds with
+/// `1 mons = 30 days` and `1 days = 24 hours`. Intervals of the same length but with
+/// different fields (`1 mons` and `30 days`) are then ordered field-wise by months,
+/// days and microseconds, so the ordering stays consistent with `Eq`.
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Interval) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Interval {
+    fn cmp(&self, other: &Interval) -> Ordering {
+        self.total_microseconds().cmp(&other.total_microseconds())
+            .then(self.pg.months.cmp(&other.pg.months))
+            .then(self.pg.days.cmp(&other.pg.days))
+            .then(self.pg.microseconds.cmp(&other.pg.microseconds))
+    }
+}
+
+/// Compares the interval's `total_microseconds`, counting every month as 30 days and
+/// every day as 24 hours, with the duration at nanosecond precision, so a duration with
+/// a sub-microsecond remainder never equals an interval. A negative interval never
+/// equals a `Duration`.
+impl PartialEq<Duration> for Interval {
+    fn eq(&self, other: &Duration) -> bool {
+        self.total_microseconds() * 1_000 == other.as_nanos() as i128
+    }
+}
+
+impl PartialEq<Interval> for Duration {
+    fn eq(&self, other: &Interval) -> bool {
+        other == self
+    }
+}
+
+/// Orders by the same nanosecond comparison as `PartialEq<Duration>`, which places every
+/// negative interval below any `Duration`.
+impl PartialOrd<Duration> for Interval {
+    fn partial_cmp(&self, other: &Duration) -> Option<Ordering> {
+        Some((self.total_microseconds() * 1_000).cmp(&(other.as_nanos() as i128)))
+    }
+}
+
+impl PartialOrd<Interval> for Duration {
+    fn partial_cmp(&self, other: &Interval) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl Hash for Interval {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.pg.months.hash(state);
+        self.pg.days.hash(state);
+        self.pg.microseconds.hash(state);
+    }
+}
+
+/// Adds field-wise. Overflow wraps, as it does for `+=` and `Sum`; use
+/// `Interval::checked_add` or `Interval::saturating_add` instead.
+impl Add for Interval {
+    type Output = Interval;
+
+    fn add(self, rhs: Interval) -> Interval {
+        Interval::from_components(
+            self.pg.months.wrapping_add(rhs.pg.months),
+            self.pg.days.wrapping_add(rhs.pg.days),
+            self.pg.microseconds.wrapping_add(rhs.pg.microseconds),
+        )
+    }
+}
+
+impl<'a> Add<&'a Interval> for &'a Interval {
+    type Output = Interval;
+
+    fn add(self, rhs: &Interval) -> Interval {
+        *self + *rhs
+    }
+}
+
+/// Subtracts field-wise. Overflow wraps, as it does for `-=`; use
+/// `Interval::checked_sub` or `Interval::saturating_sub` instead.
+impl Sub for Interval {
+    type Output = Interval;
+
+    fn sub(self, rhs: Interval) -> Interval {
+        Interval::from_components(
+            self.pg.months.wrapping_sub(rhs.pg.months),
+            self.pg.days.wrapping_sub(rhs.pg.days),
+            self.pg.microseconds.wrapping_sub(rhs.pg.microseconds),
+        )
+    }
+}
+
+impl<'a> Sub<&'a Interval> for &'a Interval {
+    type Output = Interval;
+
+    fn sub(self, rhs: &Interval) -> Interval {
+        *self - *rhs
+    }
+}
+
+impl AddAssign for Interval {
+    fn add_assign(&mut self, rhs: Interval) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<&Interval> for Interval {
+    fn add_assign(&mut self, rhs: &Interval) {
+        *self += *rhs;
+    }
+}
+
+impl SubAssign for Interval {
+    fn sub_assign(&mut self, rhs: Interval) {
+        *self = *self - rhs;
+    }
+}
+
+impl SubAssign<&Interval> for Interval {
+    fn sub_assign(&mut self, rhs: &Interval) {
+        *self -= *rhs;
+    }
+}
+
+/// Negates each field independently. `i32::MIN` and `i64::MIN` have no positive
+/// counterpart, so negation wraps (`wrapping_neg`) and leaves them unchanged.
+impl Neg for Interval {
+    type Output = Interval;
+
+    fn neg(self) -> Interval {
+        Interval::from_components(
+            self.pg.months.wrapping_neg(),
+            self.pg.days.wrapping_neg(),
+            self.pg.microseconds.wrapping_neg(),
+        )
+    }
+}
+
+impl Neg for &Interval {
+    type Output = Interval;
+
+    fn neg(self) -> Interval {
+        -*self
+    }
+}
+
+/// Multiplies each field by the scalar. Overflow wraps; use `Interval::checked_mul`
+/// to detect it instead.
+impl Mul<i32> for Interval {
+    type Output = Interval;
+
+    fn mul(self, rhs: i32) -> Interval {
+        Interval::from_components(
+            self.pg.months.wrapping_mul(rhs),
+            self.pg.days.wrapping_mul(rhs),
+            self.pg.microseconds.wrapping_mul(i64::from(rhs)),
+        )
+    }
+}
+
+/// Scales the interval the way Postgres' `interval * float8` does: the fractional part
+/// of the scaled months spills into days (at 30 days per month) and the fractional part
+/// of the scaled days spills into microseconds (at 24 hours per day), which are then
+/// rounded to the nearest microsecond with ties to even. When the two day fractions add
+/// up to a whole day or more, that day is carried back into the days, so `1 mons 1 days`
+/// times `0.97` is `30 days 01:40:48` rather than `29 days 25:40:48`. Non-finite factors
+/// follow Rust's saturating float-to-integer casts, so NaN yields zero and infinities
+/// saturate; use `Interval::checked_mul_f64` to reject them instead.
+impl Mul<f64> for Interval {
+    type Output = Interval;
+
+    fn mul(self, rhs: f64) -> Interval {
+        let (months, days, microseconds) = self.scale_f64(rhs);
+        Interval::from_components(
+            months as i32,
+            days.iter().fold(0i32, |sum, &days| sum.saturating_add(days as i32)),
+            microseconds.round_ties_even() as i64,
+        )
+    }
+}
+
+impl Mul<Interval> for i32 {
+    type Output = Interval;
+
+    fn mul(self, rhs: Interval) -> Interval {
+        rhs * self
+    }
+}
+
+/// Divides the interval like Postgres does, carrying remainders down instead of dropping
+/// them: months that do not divide evenly become days (at 30 days per month) and days
+/// that do not divide evenly become microseconds (at 24 hours per day). Only the
+/// remainder below one microsecond is truncated. Panics if `rhs` is zero; a result that
+/// does not fit its field (`i32::MIN / -1`) wraps.
+impl Div<i32> for Interval {
+    type Output = Interval;
+
+    fn div(self, rhs: i32) -> Interval {
+        let rhs = i128::from(rhs);
+        let months = i128::from(self.pg.months);
+        let days = i128::from(self.pg.days) + months % rhs * DAYS_PER_MONTH;
+        let microseconds = i128::from(self.pg.microseconds) + days % rhs * MICROSECONDS_PER_DAY;
+        Interval::from_components(
+            (months / rhs) as i32,
+            (days / rhs) as i32,
+            (microseconds / rhs) as i64,
+        )
+    }
+}
+
+impl Sum for Interval {
+    fn sum<I: Iterator<Item = Interval>>(iter: I) -> Interval {
+        iter.fold(Interval::default(), |acc, interval| acc + interval)
+    }
+}
+
+impl<'a> Sum<&'a Interval> for Interval {
+    fn sum<I: Iterator<Item = &'a Interval>>(iter: I) -> Interval {
+        iter.fold(Interval::default(), |acc, interval| acc + *interval)
+    }
+}
+
+impl<'a> FromSql<'a> for Interval {
+    fn from_sql(_: &Type, raw: &[u8]) -> Result<Interval, Box<dyn Error + Sync + Send>> {
+        if raw.len() != 16 {
+            return Err(format!("invalid interval length: expected 16 bytes, got {}", raw.len()).into());
+        }
+        Ok(Interval::from_components(
+            i32::from_be_bytes(raw[12..16].try_into().unwrap()),
+            i32::from_be_bytes(raw[8..12].try_into().unwrap()),
+            i64::from_be_bytes(raw[0..8].try_into().unwrap()),
+        ))
+    }
+
+    accepts!(INTERVAL);
+}
+
+impl ToSql for Interval {
+    // The wire format is an 8-byte microsecond count followed by 4-byte days and 4-byte
+    // months, all big-endian; `FromSql` and `bytes()` rely on the same layout.
+    fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let start = w.len();
+        w.extend_from_slice(self.pg.microseconds.to_be_bytes().as_slice());
+        w.extend_from_slice(self.pg.days.to_be_bytes().as_slice());
+        w.extend_from_slice(self.pg.months.to_be_bytes().as_slice());
+        debug_assert_eq!(w.len() - start, 16);
+        Ok(IsNull::No)
+    }
+
+    accepts!(INTERVAL);
+    to_sql_checked!();
+}
+
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for Interval {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        <sqlx::postgres::types::PgInterval as sqlx::Type<sqlx::Postgres>>::type_info()
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl sqlx::postgres::PgHasArrayType for Interval {
+    fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+        <sqlx::postgres::types::PgInterval as sqlx::postgres::PgHasArrayType>::array_type_info()
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'q> sqlx::Encode<'q, sqlx::Postgres> for Interval {
+    fn encode_by_ref(&self, buf: &mut sqlx::postgres::PgArgumentBuffer) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        buf.extend_from_slice(&self.bytes());
+        Ok(sqlx::encode::IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        16
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'r> sqlx::Decode<'r, sqlx::Postgres> for Interval {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Interval, sqlx::error::BoxDynError> {
+        Interval::decode_sqlx(value.format(), value.as_bytes()?)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl Interval {
+    fn decode_sqlx(format: sqlx::postgres::PgValueFormat, raw: &[u8]) -> Result<Interval, sqlx::error::BoxDynError> {
+        match format {
+            sqlx::postgres::PgValueFormat::Binary => Interval::from_sql(&Type::INTERVAL, raw),
+            sqlx::postgres::PgValueFormat::Text => Interval::from_sql_text(raw),
+        }
+    }
+}
+
+#[cfg(feature = "diesel")]
+impl diesel::serialize::ToSql<diesel::sql_types::Interval, diesel::pg::Pg> for Interval {
+    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {
+        std::io::Write::write_all(out, &self.bytes())?;
+        Ok(diesel::serialize::IsNull::No)
+    }
+}
+
+#[cfg(feature = "diesel")]
+impl diesel::deserialize::FromSql<diesel::sql_types::Interval, diesel::pg::Pg> for Interval {
+    fn from_sql(value: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Interval> {
+        <Interval as FromSql>::from_sql(&Type::INTERVAL, value.as_bytes())
+    }
+}
+
+const FIELDS: &[&str] = &["m", "d", "us"];
+const VERBOSE_FIELDS: &[&str] = &["months", "days", "microseconds"];
+
+fn visit_interval_map<'de, V>(mut visitor: V, fields: &'static [&'static str]) -> Result<Interval, V::Error>
+    where
+        V: serde::de::MapAccess<'de>,
+{
+    let mut months = None;
+    let mut days = None;
+    let mut microseconds = None;
+
+    while let Some(key) = visitor.next_key::<&str>()? {
+        if key == fields[0] {
+            if months.is_some() {
+                return Err(serde::de::Error::duplicate_field(fields[0]));
+            }
+            months = Some(visitor.next_value()?);
+        } else if key == fields[1] {
+            if days.is_some() {
+                return Err(serde::de::Error::duplicate_field(fields[1]));
+            }
+            days = Some(visitor.next_value()?);
+        } else if key == fields[2] {
+            if microseconds.is_some() {
+                return Err(serde::de::Error::duplicate_field(fields[2]));
+            }
+            microseconds = Some(visitor.next_value()?);
+        } else {
+            return Err(serde::de::Error::unknown_field(key, fields));
+        }
+    }
+
+    let months = months.ok_or_else(|| serde::de::Error::missing_field(fields[0]))?;
+    let days = days.ok_or_else(|| serde::de::Error::missing_field(fields[1]))?;
+    let microseconds = microseconds.ok_or_else(|| serde::de::Error::missing_field(fields[2]))?;
+
+    Ok(Interval::from_components(months, days, microseconds))
+}
+
+/// Human-readable formats get a `{"m":..,"d":..,"us":..}` object and binary formats a
+/// `(months, days, microseconds)` tuple. With the `serde-iso` feature, human-readable
+/// formats get an ISO 8601 string such as `"P1Y2M3DT4H"` instead, as `IntervalIso` does.
+/// The two are exclusive: cargo unifies features across the build, so enabling
+/// `serde-iso` anywhere switches every `Interval` in the program, and the object form
+/// is then only read, never written. Binary formats keep the tuple either way.
+impl Serialize for Interval {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+    {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_tuple(3)?;
+            state.serialize_element(&self.pg.months)?;
+            state.serialize_element(&self.pg.days)?;
+            state.serialize_element(&self.pg.microseconds)?;
+            return state.end();
+        }
+        if cfg!(feature = "serde-iso") {
+            return serializer.serialize_str(&self.to_iso8601());
+        }
+        let mut state = serializer.serialize_struct("Interval", 3)?;
+        state.serialize_field(FIELDS[0], &self.pg.months)?;
+        state.serialize_field(FIELDS[1], &self.pg.days)?;
+        state.serialize_field(FIELDS[2], &self.pg.microseconds)?;
+        state.end()
+    }
+}
+
+/// Accepts everything `Serialize` writes. Strings are read in the Postgres format, or
+/// as ISO 8601 with the `serde-iso` feature, and a bare integer is read as microseconds.
+impl<'de> Deserialize<'de> for Interval {
+    fn deserialize<D>(deserializer: D) -> Result<Interval, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+    {
+        struct IntervalVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for IntervalVisitor {
+            type Value = Interval;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a string or integer of microseconds representing an interval")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Interval, E>
+                where
+                    E: serde::de::Error,
+            {
+                if cfg!(feature = "serde-iso") {
+                    return Interval::from_iso8601(value).map_err(serde::de::Error::custom);
+                }
+                Interval::new(value).map_err(serde::de::Error::custom)
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Interval, E>
+                where
+                    E: serde::de::Error,
+            {
+                Ok(Interval::from_components(0, 0, value))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Interval, E>
+                where
+                    E: serde::de::Error,
+            {
+                i64::try_from(value)
+                    .map(|microseconds| Interval::from_components(0, 0, microseconds))
+                    .map_err(|_| serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_seq<V>(self, mut visitor: V) -> Result<Interval, V::Error>
+                where
+                    V: serde::de::SeqAccess<'de>,
+            {
+                let months = visitor.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                let days = visitor.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                let microseconds = visitor.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(2, &self))?;
+                Ok(Interval::from_components(months, days, microseconds))
+            }
+
+            fn visit_map<V>(self, visitor: V) -> Result<Interval, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                visit_interval_map(visitor, FIELDS)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(IntervalVisitor)
+        } else {
+            deserializer.deserialize_tuple(3, IntervalVisitor)
+        }
+    }
+}
+
+/// Serializes an `Interval` with the field names spelled out, as
+/// `{"months":..,"days":..,"microseconds":..}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IntervalVerbose(pub Interval);
+
+impl From<Interval> for IntervalVerbose {
+    fn from(interval: Interval) -> IntervalVerbose {
+        IntervalVerbose(interval)
+    }
+}
+
+impl From<IntervalVerbose> for Interval {
+    fn from(interval: IntervalVerbose) -> Interval {
+        interval.0
+    }
+}
+
+impl Serialize for IntervalVerbose {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("IntervalVerbose", 3)?;
+        state.serialize_field(VERBOSE_FIELDS[0], &self.0.pg.months)?;
+        state.serialize_field(VERBOSE_FIELDS[1], &self.0.pg.days)?;
+        state.serialize_field(VERBOSE_FIELDS[2], &self.0.pg.microseconds)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for IntervalVerbose {
+    fn deserialize<D>(deserializer: D) -> Result<IntervalVerbose, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+    {
+        struct IntervalVerboseVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for IntervalVerboseVisitor {
+            type Value = IntervalVerbose;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a map with months, days and microseconds")
+            }
+
+            fn visit_map<V>(self, visitor: V) -> Result<IntervalVerbose, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                visit_interval_map(visitor, VERBOSE_FIELDS).map(IntervalVerbose)
+            }
+        }
+
+        deserializer.deserialize_struct("IntervalVerbose", VERBOSE_FIELDS, IntervalVerboseVisitor)
+    }
+}
+
+/// The raw fields of an `Interval` with derived serde impls, for embedding in your own
+/// derive-based structs where standard attributes such as `#[serde(rename_all)]` should
+/// control the field names. `Interval` itself keeps its hand-written `{"m","d","us"}`
+/// representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct IntervalParts {
+    pub months: i32,
+    pub days: i32,
+    pub microseconds: i64,
+}
+
+impl From<Interval> for IntervalParts {
+    fn from(interval: Interval) -> IntervalParts {
+        IntervalParts {
+            months: interval.pg.months,
+            days: interval.pg.days,
+            microseconds: interval.pg.microseconds,
+        }
+    }
+}
+
+impl From<IntervalParts> for Interval {
+    fn from(parts: IntervalParts) -> Interval {
+        Interval::from_components(parts.months, parts.days, parts.microseconds)
+    }
+}
+
+/// Serializes an `Interval` as an ISO 8601 duration string such as `"P1Y2M3DT4H"`
+/// instead of the default `{"m":..,"d":..,"us":..}` object. Its `FromStr` parses ISO 8601
+/// too, where `Interval`'s parses the Postgres format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IntervalIso(pub Interval);
+
+/// `IntervalIso` under the name used for parsing, as in `"P1Y".parse::<Iso8601Interval>()`.
+pub type Iso8601Interval = IntervalIso;
+
+impl FromStr for IntervalIso {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Interval::from_iso8601(s).map(IntervalIso)
+    }
+}
+
+impl From<Interval> for IntervalIso {
+    fn from(interval: Interval) -> IntervalIso {
+        IntervalIso(interval)
+    }
+}
+
+impl From<IntervalIso> for Interval {
+    fn from(interval: IntervalIso) -> Interval {
+        interval.0
+    }
+}
+
+impl Serialize for IntervalIso {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0.to_iso8601())
+    }
+}
+
+impl<'de> Deserialize<'de> for IntervalIso {
+    fn deserialize<D>(deserializer: D) -> Result<IntervalIso, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+    {
+        struct IntervalIsoVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for IntervalIsoVisitor {
+            type Value = IntervalIso;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("an ISO 8601 duration string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<IntervalIso, E>
+                where
+                    E: serde::de::Error,
+            {
+                Interval::from_iso8601(value).map(IntervalIso).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(IntervalIsoVisitor)
+    }
+}
+
+/// Serializes an `Interval` as its `total_microseconds` for use with
+/// `#[serde(with = "pg_interval_sql_json_binding::as_total_microseconds")]`. Human-readable
+/// formats get a decimal string, since the total can exceed what JSON numbers hold
+/// exactly, and binary formats get an `i128`. The round trip is lossy: months and days
+/// are folded in at 30 days per month and 24 hours per day and come back as
+/// microseconds only, so `1 mons` deserializes as `720:00:00`. A total beyond the `i64`
+/// range fails to deserialize.
+pub mod as_total_microseconds {
+    use super::Interval;
+    use serde::{Deserializer, Serializer};
+    use std::convert::TryFrom;
+    use std::fmt::Formatter;
+
+    pub fn serialize<S>(interval: &Interval, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&interval.total_microseconds().to_string())
+        } else {
+            serializer.serialize_i128(interval.total_microseconds())
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Interval, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        struct TotalMicrosecondsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for TotalMicrosecondsVisitor {
+            type Value = Interval;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("a total number of microseconds")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Interval, E>
+                where
+                    E: serde::de::Error,
+            {
+                let total = value.parse::<i128>().map_err(E::custom)?;
+                self.visit_i128(total)
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Interval, E>
+                where
+                    E: serde::de::Error,
+            {
+                Ok(Interval::from_components(0, 0, value))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Interval, E>
+                where
+                    E: serde::de::Error,
+            {
+                self.visit_i128(i128::from(value))
+            }
+
+            fn visit_i128<E>(self, value: i128) -> Result<Interval, E>
+                where
+                    E: serde::de::Error,
+            {
+                i64::try_from(value)
+                    .map(|microseconds| Interval::from_components(0, 0, microseconds))
+                    .map_err(|_| E::custom(format!("total of {} microseconds is out of range for an interval", value)))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(TotalMicrosecondsVisitor)
+        } else {
+            deserializer.deserialize_i128(TotalMicrosecondsVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_from_str() {
+        let interval = Interval::new("1 mons 2 days 3 seconds").unwrap();
+        assert_eq!(interval.pg.months, 1);
+        assert_eq!(interval.pg.days, 2);
+        assert_eq!(interval.pg.microseconds, 3000000);
+    }
+
+    #[test]
+    fn test_interval_from_str_or_seconds() {
+        assert_eq!(Interval::from_str_or_seconds("90").unwrap(), Interval::from_seconds(90));
+        assert_eq!(Interval::from_str_or_seconds("1.5").unwrap(), Interval::from_millis(1500));
+        assert_eq!(Interval::from_str_or_seconds("-2").unwrap(), Interval::from_seconds(-2));
+        assert_eq!(Interval::from_str_or_seconds("1 mons").unwrap(), Interval::from_months(1));
+        assert_eq!(Interval::from_str_or_seconds("1 monthss").unwrap_err(), Interval::new("1 monthss").unwrap_err());
+        assert!(Interval::from_str_or_seconds("NaN").is_err());
+        assert!(Interval::from_str_or_seconds("1e30").is_err());
+    }
+
+    #[test]
+    fn test_interval_parse_postgres_verbose() {
+        assert_eq!(Interval::parse_postgres_verbose("@ 1 year 2 mons").unwrap(), Interval::from_months(14));
+        assert_eq!(Interval::parse_postgres_verbose("@ 1 year ago").unwrap(), Interval::new("-1 years").unwrap());
+        assert_eq!(
+            Interval::parse_postgres_verbose("@ 3 days 4 hours 5 mins 6.5 secs ago").unwrap(),
+            Interval::new("-3 days -4 hours -5 minutes -6.5 seconds").unwrap()
+        );
+        assert_eq!(Interval::parse_postgres_verbose("1 mon 1 day").unwrap(), Interval::new("1 mons 1 days").unwrap());
+        assert_eq!(Interval::parse_postgres_verbose("@ 0").unwrap(), Interval::ZERO);
+        assert!(Interval::parse_postgres_verbose("@ 1 fortnight").is_err());
+    }
+
+    #[test]
+    fn test_interval_try_from_str() {
+        assert_eq!(Interval::try_from("1 mons 2 days").unwrap(), Interval::from_components(1, 2, 0));
+        assert_eq!(Interval::try_from(String::from("3 seconds")).unwrap(), Interval::from_seconds(3));
+        assert_eq!(Interval::try_from("1 monthss").unwrap_err().kind(), ParseErrorKind::InvalidInterval);
+        assert!(Interval::try_from(String::from("abc days")).is_err());
+    }
+
+    #[test]
+    fn test_interval_from_sql() {
+        let interval = Interval::from_sql(&Type::INTERVAL, &[0, 0, 0, 0, 0, 45, 198, 192, 0, 0, 0, 2, 0, 0, 0, 1]).unwrap();
+        assert_eq!(interval.pg.months, 1);
+        assert_eq!(interval.pg.days, 2);
+        assert_eq!(interval.pg.microseconds, 3000000);
+    }
+
+    #[test]
+    fn test_interval_from_sql_text() {
+        let binary = Interval::from_sql(&Type::INTERVAL, &[0, 0, 0, 2, 131, 186, 236, 0, 0, 0, 0, 2, 0, 0, 0, 1]).unwrap();
+        let text = Interval::from_sql_text(b"1 mon 2 days 03:00:00").unwrap();
+        assert_eq!(binary, text);
+        assert_eq!(Interval::from_sql_text(b"1 year").unwrap(), Interval::from_months(12));
+        assert_eq!(Interval::from_sql_text(b"1 day").unwrap(), Interval::from_days(1));
+        assert_eq!(Interval::from_sql_text(b"-1 years +2 mons -3 days +04:00:00").unwrap(), Interval::from_components(-10, -3, 4 * 3600000000));
+        assert!(Interval::from_sql_text(&[0xff, 0xfe]).is_err());
+        assert!(Interval::from_sql_text(b"1 fortnight").is_err());
+    }
+
+    #[test]
+    fn test_interval_from_sql_invalid_length() {
+        let err = Interval::from_sql(&Type::INTERVAL, &[0, 0, 0, 0, 0, 45, 198, 192, 0, 0]).unwrap_err();
+        assert_eq!(err.to_string(), "invalid interval length: expected 16 bytes, got 10");
+        assert!(Interval::from_sql(&Type::INTERVAL, &[0; 17]).is_err());
+    }
+
+    #[test]
+    fn test_interval_to_sql() {
+        let interval = Interval {
+            pg: pg_interval::Interval {
+                months: 1,
+                days: 2,
+                microseconds: 3000000,
+            }
+        };
+        let mut buf = BytesMut::new();
+        interval.to_sql(&Type::INTERVAL, &mut buf).unwrap();
+        assert_eq!(buf.as_ref(), &[0, 0, 0, 0, 0, 45, 198, 192, 0, 0, 0, 2, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_interval_to_sql_wire_format() {
+        let cases: [(Interval, [u8; 16]); 4] = [
+            (Interval::from_components(-1, -2, -1), [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 255, 255, 255]),
+            (Interval::from_components(-14, 3, -3000000), [255, 255, 255, 255, 255, 210, 57, 64, 0, 0, 0, 3, 255, 255, 255, 242]),
+            (Interval::from_components(i32::MIN, 0, i64::MAX), [127, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 128, 0, 0, 0]),
+            (Interval::from_components(1, -1, 0), [0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 1]),
+        ];
+        for (interval, expected) in cases {
+            let mut buf = BytesMut::new();
+            interval.to_sql(&Type::INTERVAL, &mut buf).unwrap();
+            assert_eq!(buf.as_ref(), &expected);
+            assert_eq!(interval.bytes(), expected);
+            assert_eq!(Interval::from_sql(&Type::INTERVAL, &expected).unwrap(), interval);
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_interval_sql_round_trip(months: i32, days: i32, microseconds: i64) {
+            let interval = Interval::from_components(months, days, microseconds);
+            let mut buf = BytesMut::new();
+            interval.to_sql(&Type::INTERVAL, &mut buf).unwrap();
+            proptest::prop_assert_eq!(buf.to_vec(), interval.bytes());
+            proptest::prop_assert_eq!(Interval::from_sql(&Type::INTERVAL, buf.as_ref()).unwrap(), interval);
+        }
+    }
+
+    #[test]
+    fn test_interval_bytes() {
+        let interval = Interval::new("1 mons 2 days 3 seconds").unwrap();
+        let mut buf = BytesMut::new();
+        interval.to_sql(&Type::INTERVAL, &mut buf).unwrap();
+        assert_eq!(interval.bytes(), vec![0, 0, 0, 0, 0, 45, 198, 192, 0, 0, 0, 2, 0, 0, 0, 1]);
+        assert_eq!(interval.bytes(), buf.as_ref());
+    }
+
+    #[test]
+    #[cfg(not(feature = "serde-iso"))]
+    fn test_interval_jsonb_bytes() {
+        let interval = Interval::new("1 mons 2 days 3 seconds").unwrap();
+        let buf = interval.to_jsonb_bytes();
+        assert_eq!(buf, br#"{"m":1,"d":2,"us":3000000}"#);
+        assert_eq!(Interval::from_jsonb_bytes(&buf).unwrap(), interval);
+        assert!(Interval::from_jsonb_bytes(br#"{"m":1,"d":2}"#).is_err());
+    }
+
+    #[test]
+    fn test_interval_array_sql_round_trip() {
+        let intervals = vec![
+            Interval::new("1 mons 2 days 3 seconds").unwrap(),
+            Interval::new("-4 days").unwrap(),
+            Interval::default(),
+        ];
+        let mut buf = BytesMut::new();
+        intervals.to_sql(&Type::INTERVAL_ARRAY, &mut buf).unwrap();
+        let decoded = Vec::<Interval>::from_sql(&Type::INTERVAL_ARRAY, buf.as_ref()).unwrap();
+        assert_eq!(decoded, intervals);
+        assert!(<Vec<Interval> as ToSql>::accepts(&Type::INTERVAL_ARRAY));
+        assert!(<Vec<Interval> as FromSql>::accepts(&Type::INTERVAL_ARRAY));
+        assert!(!<Vec<Interval> as FromSql>::accepts(&Type::TEXT_ARRAY));
+    }
+
+    #[test]
+    fn test_interval_option_sql() {
+        let raw: &[u8] = &[0, 0, 0, 0, 0, 45, 198, 192, 0, 0, 0, 2, 0, 0, 0, 1];
+        assert_eq!(Option::<Interval>::from_sql_nullable(&Type::INTERVAL, None).unwrap(), None);
+        assert_eq!(
+            Option::<Interval>::from_sql_nullable(&Type::INTERVAL, Some(raw)).unwrap(),
+            Some(Interval::new("1 mons 2 days 3 seconds").unwrap())
+        );
+        assert!(Interval::from_sql_nullable(&Type::INTERVAL, None).is_err());
+        assert!(Interval::from_sql(&Type::INTERVAL, &[]).is_err());
+
+        let mut buf = BytesMut::new();
+        let is_null = None::<Interval>.to_sql(&Type::INTERVAL, &mut buf).unwrap();
+        assert!(matches!(is_null, IsNull::Yes));
+        assert!(buf.is_empty());
+        let is_null = Some(Interval::new("1 mons 2 days 3 seconds").unwrap()).to_sql(&Type::INTERVAL, &mut buf).unwrap();
+        assert!(matches!(is_null, IsNull::No));
+        assert_eq!(buf.as_ref(), raw);
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[test]
+    fn test_interval_sqlx() {
+        let interval = Interval::new("1 mons 2 days 3 seconds").unwrap();
+        let mut buf = sqlx::postgres::PgArgumentBuffer::default();
+        let is_null = sqlx::Encode::<sqlx::Postgres>::encode_by_ref(&interval, &mut buf).unwrap();
+        assert!(matches!(is_null, sqlx::encode::IsNull::No));
+        assert_eq!(buf.as_slice(), &[0, 0, 0, 0, 0, 45, 198, 192, 0, 0, 0, 2, 0, 0, 0, 1]);
+        assert_eq!(<Interval as sqlx::Type<sqlx::Postgres>>::type_info(), sqlx::postgres::PgTypeInfo::with_name("INTERVAL"));
+
+        // sqlx offers no public way to build a PgValueRef, so feed both wire formats to the
+        // function `Decode` hands the value's format and bytes to.
+        let binary = Interval::decode_sqlx(sqlx::postgres::PgValueFormat::Binary, buf.as_slice()).unwrap();
+        assert_eq!(binary, interval);
+        let text = Interval::decode_sqlx(sqlx::postgres::PgValueFormat::Text, b"1 mon 2 days 00:00:03").unwrap();
+        assert_eq!(text, interval);
+
+        // Text output of a Postgres 15 server for each value.
+        let decode_text = |raw: &[u8]| Interval::decode_sqlx(sqlx::postgres::PgValueFormat::Text, raw).unwrap();
+        assert_eq!(decode_text(b"1 year 2 mons 3 days 04:05:06.5"), Interval::from_components(14, 3, 4 * 3600000000 + 5 * 60000000 + 6500000));
+        assert_eq!(decode_text(b"-10 mons -3 days +04:00:00"), Interval::from_components(-10, -3, 4 * 3600000000));
+        assert_eq!(decode_text(b"-1 years -2 mons +5 days"), Interval::from_components(-14, 5, 0));
+        assert_eq!(decode_text(b"1 day -00:00:01.5"), Interval::from_components(0, 1, -1500000));
+        assert_eq!(decode_text(b"00:00:00"), Interval::ZERO);
+        assert!(Interval::decode_sqlx(sqlx::postgres::PgValueFormat::Binary, b"1 day").is_err());
+    }
+
+    #[cfg(feature = "diesel")]
+    #[test]
+    fn test_interval_diesel() {
+        struct NoLookup;
+
+        impl diesel::pg::PgMetadataLookup for NoLookup {
+            fn lookup_type(&mut self, _: &str, _: Option<&str>) -> diesel::pg::PgTypeMetadata {
+                unreachable!("interval has a fixed OID")
+            }
+        }
+
+        let bytes = vec![0, 0, 0, 0, 0, 45, 198, 192, 0, 0, 0, 2, 0, 0, 0, 1];
+        let oid = std::num::NonZeroU32::new(1186).unwrap();
+        let value = diesel::pg::PgValue::new(&bytes, &oid);
+        let interval = <Interval as diesel::deserialize::FromSql<diesel::sql_types::Interval, diesel::pg::Pg>>::from_sql(value).unwrap();
+        assert_eq!(interval, Interval::new("1 mons 2 days 3 seconds").unwrap());
+        let value = diesel::pg::PgValue::new(&bytes[..10], &oid);
+        assert!(<Interval as diesel::deserialize::FromSql<diesel::sql_types::Interval, diesel::pg::Pg>>::from_sql(value).is_err());
+
+        let mut collector = diesel::query_builder::bind_collector::RawBytesBindCollector::<diesel::pg::Pg>::new();
+        diesel::query_builder::BindCollector::push_bound_value::<diesel::sql_types::Interval, Interval>(&mut collector, &interval, &mut NoLookup).unwrap();
+        assert_eq!(collector.binds, vec![Some(bytes)]);
+    }
+
+    #[test]
+    fn test_interval_display() {
+        let interval = Interval {
+            pg: pg_interval::Interval {
+                months: 14,
+                days: 3,
+                microseconds: 4 * 3600000000 + 5 * 60000000 + 6 * 1000000 + 7 * 1000 + 8,
+            }
+        };
+        assert_eq!(format!("{}", interval), "1 year 2 mons 3 days 4 hours 5 minutes 6 seconds 7 milliseconds 8 microseconds");
+
+        let interval = Interval {
+            pg: pg_interval::Interval {
+                months: 1,
+                days: 2,
+                microseconds: 3 * 1000000,
+            }
+        };
+        assert_eq!(interval.to_string(), "1 mon 2 days 3 seconds");
+
+        let interval = Interval::from_components(13, 1, 3600000000 + 60000000 + 1000000 + 1000 + 1);
+        assert_eq!(interval.to_string(), "1 year 1 mon 1 day 1 hour 1 minute 1 second 1 millisecond 1 microsecond");
+
+        let interval = Interval::from_components(26, 2, 2 * 3600000000 + 2 * 60000000 + 2 * 1000000 + 2 * 1000 + 2);
+        assert_eq!(interval.to_string(), "2 years 2 mons 2 days 2 hours 2 minutes 2 seconds 2 milliseconds 2 microseconds");
+
+        let interval = Interval::from_components(-14, -3, -(4 * 3600000000 + 5 * 60000000 + 6 * 1000000 + 7 * 1000 + 8));
+        assert_eq!(interval.to_string(), "-1 years -2 mons -3 days -4 hours -5 minutes -6 seconds -7 milliseconds -8 microseconds");
+
+        let interval = Interval::from_components(0, 0, -(4 * 3600000000 + 7000 + 8));
+        assert_eq!(interval.to_string(), "-4 hours -7 milliseconds -8 microseconds");
+
+        let interval = Interval::from_components(0, 1, i64::MIN);
+        assert_eq!(interval.to_string(), "1 day -2562047788 hours -54 seconds -775 milliseconds -808 microseconds");
+
+        let interval = Interval::from_components(-1, 2, -3 * 1000000);
+        assert_eq!(interval.to_string(), "-1 mons 2 days -3 seconds");
+
+        let interval = Interval::from_components(11, -1, 3600000000);
+        assert_eq!(interval.to_string(), "11 mons -1 days 1 hour");
+
+        assert_eq!(Interval::default().to_string(), "0 seconds");
+        assert_eq!(Interval::new("0 seconds").unwrap().to_string(), "0 seconds");
+    }
+
+    #[test]
+    fn test_interval_display_from_str_round_trip() {
+        for interval in [
+            Interval::default(),
+            Interval::from_components(13, 1, 3600000000 + 60000000 + 1000000 + 1000 + 1),
+            Interval::from_components(-14, -3, -(4 * 3600000000 + 5 * 60000000 + 6 * 1000000 + 7 * 1000 + 8)),
+            Interval::from_components(11, -1, 3600000000),
+            Interval::from_components(-1, 2, -3 * 1000000),
+            Interval::from_components(0, 1, i64::MIN),
+            Interval::MAX,
+        ] {
+            assert_eq!(interval.to_string().parse::<Interval>().unwrap(), interval);
+            assert_eq!(format!("{:#}", interval).parse::<Interval>().unwrap(), interval);
+            assert_eq!(Interval::from_sql_text(format!("{:#}", interval).as_bytes()).unwrap(), interval);
+        }
+    }
+
+    #[test]
+    fn test_interval_debug() {
+        let interval = Interval::new("1 mons 2 days 3 seconds").unwrap();
+        assert_eq!(
+            format!("{:?}", interval),
+            r#"Interval { months: 1, days: 2, microseconds: 3000000, display: "1 mon 2 days 3 seconds" }"#
+        );
+        assert!(format!("{:?}", Some(interval)).contains(r#"display: "1 mon 2 days 3 seconds""#));
+        assert!(format!("{:#?}", interval).contains("display: \"1 mon 2 days 3 seconds\",\n"));
+    }
+
+    #[test]
+    fn test_interval_display_alternate() {
+        let interval = Interval::new("1 years 2 mons 3 days 4 hours 5 minutes 6 seconds").unwrap();
+        assert_eq!(format!("{}", interval), "1 year 2 mons 3 days 4 hours 5 minutes 6 seconds");
+        assert_eq!(format!("{:#}", interval), "1 year 2 mons 3 days 04:05:06");
+        assert_eq!(format!("{:#}", Interval::zero()), "00:00:00");
+        assert_eq!(format!("{:#}", Interval::from_components(0, -1, 2 * 3600000000)), "-1 days +02:00:00");
+    }
+
+    #[test]
+    fn test_interval_components() {
+        let interval = Interval::new("1 years 2 mons 3 days 4 hours 5 minutes 6.007008 seconds").unwrap();
+        assert_eq!(interval.components(), IntervalComponents {
+            years: 1,
+            months: 2,
+            days: 3,
+            hours: 4,
+            minutes: 5,
+            seconds: 6,
+            milliseconds: 7,
+            microseconds: 8,
+        });
+
+        let components = Interval::from_components(-14, -3, -90_500_001).components();
+        assert_eq!((components.years, components.months, components.days), (-1, -2, -3));
+        assert_eq!((components.minutes, components.seconds, components.milliseconds, components.microseconds), (-1, -30, -500, -1));
+        assert_eq!(Interval::zero().components(), IntervalComponents::default());
+    }
+
+    #[test]
+    fn test_interval_format_localized() {
+        let german = UnitNames {
+            year: "Jahr",
+            years: "Jahre",
+            month: "Monat",
+            months: "Monate",
+            day: "Tag",
+            days: "Tage",
+            hour: "Stunde",
+            hours: "Stunden",
+            minute: "Minute",
+            minutes: "Minuten",
+            second: "Sekunde",
+            seconds: "Sekunden",
+            millisecond: "Millisekunde",
+            milliseconds: "Millisekunden",
+            microsecond: "Mikrosekunde",
+            microseconds: "Mikrosekunden",
+        };
+        let interval = Interval::new("1 years 2 mons 1 days 3 hours 1 minutes 0.5 seconds").unwrap();
+        assert_eq!(interval.format_localized(&german), "1 Jahr 2 Monate 1 Tag 3 Stunden 1 Minute 500 Millisekunden");
+        assert_eq!(Interval::zero().format_localized(&german), "0 Sekunden");
+        assert_eq!(interval.format_localized(&UnitNames::default()), interval.to_string());
+    }
+
+    #[test]
+    #[cfg(not(feature = "serde-iso"))]
+    fn test_interval_serialize() {
+        let interval = Interval {
+            pg: pg_interval::Interval {
+                months: 1,
+                days: 2,
+                microseconds: 3,
+            }
+        };
+        let serialized = serde_json::to_string(&interval).unwrap();
+        assert_eq!(serialized, r#"{"m":1,"d":2,"us":3}"#);
+    }
+
+    #[test]
+    fn test_interval_deserialize() {
+        let deserialized: Interval = serde_json::from_str(r#"{"m":1,"d":2,"us":3}"#).unwrap();
+        assert_eq!(deserialized.pg.months, 1);
+        assert_eq!(deserialized.pg.days, 2);
+        assert_eq!(deserialized.pg.microseconds, 3);
+    }
+
+    #[test]
+    fn test_interval_add() {
+        let interval = Interval::new("1 mons 20 days 3 seconds").unwrap() + Interval::new("15 days 2 seconds").unwrap();
+        assert_eq!(interval.pg.months, 1);
+        assert_eq!(interval.pg.days, 35);
+        assert_eq!(interval.pg.microseconds, 5000000);
+
+        let hours = Interval::new("23 hours").unwrap();
+        let interval = Add::add(&hours, &Interval::new("2 hours").unwrap());
+        assert_eq!(interval.pg.days, 0);
+        assert_eq!(interval.pg.microseconds, 25 * 3600000000);
+
+        assert_eq!(Interval::MAX + Interval::from_days(1), Interval::from_components(i32::MAX, i32::MIN, i64::MAX));
+        assert_eq!([Interval::MAX, Interval::MAX].iter().sum::<Interval>(), Interval::from_components(-2, -2, -2));
+    }
+
+    #[test]
+    fn test_interval_sub() {
+        let interval = Interval::new("1 mons 2 days 3 seconds").unwrap() - Interval::new("2 mons 1 days 5 seconds").unwrap();
+        assert_eq!(interval.pg.months, -1);
+        assert_eq!(interval.pg.days, 1);
+        assert_eq!(interval.pg.microseconds, -2000000);
+
+        let days = Interval::new("1 days").unwrap();
+        let interval = Sub::sub(&days, &Interval::new("2 days").unwrap());
+        assert_eq!(interval.pg.months, 0);
+        assert_eq!(interval.pg.days, -1);
+        assert_eq!(interval.pg.microseconds, 0);
+
+        assert_eq!(Interval::MIN - Interval::from_seconds(1), Interval::from_components(i32::MIN, i32::MIN, i64::MAX - 999_999));
+    }
+
+    #[test]
+    fn test_interval_add_assign() {
+        let intervals = vec![
+            Interval::new("1 mons 20 days 3 seconds").unwrap(),
+            Interval::new("15 days 23 hours").unwrap(),
+            Interval::new("11 mons 2 hours").unwrap(),
+        ];
+        let mut total = Interval::default();
+        for interval in intervals {
+            total += interval;
+        }
+        assert_eq!(total.pg.months, 12);
+        assert_eq!(total.pg.days, 35);
+        assert_eq!(total.pg.microseconds, 25 * 3600000000 + 3000000);
+    }
+
+    #[test]
+    fn test_interval_sub_assign() {
+        let mut interval = Interval::new("1 mons 2 days 3 seconds").unwrap();
+        interval -= Interval::new("2 mons 1 days 5 seconds").unwrap();
+        interval -= &Interval::new("1 days").unwrap();
+        assert_eq!(interval.pg.months, -1);
+        assert_eq!(interval.pg.days, 0);
+        assert_eq!(interval.pg.microseconds, -2000000);
+    }
+
+    #[test]
+    fn test_interval_neg() {
+        let interval = -Interval::new("-1 mons -2 days -3 seconds").unwrap();
+        assert_eq!(interval.pg.months, 1);
+        assert_eq!(interval.pg.days, 2);
+        assert_eq!(interval.pg.microseconds, 3000000);
+
+        let interval = -Interval {
+            pg: pg_interval::Interval {
+                months: i32::MIN,
+                days: i32::MIN,
+                microseconds: i64::MIN,
+            }
+        };
+        assert_eq!(interval.pg.months, i32::MIN);
+        assert_eq!(interval.pg.days, i32::MIN);
+        assert_eq!(interval.pg.microseconds, i64::MIN);
+    }
+
+    #[test]
+    fn test_interval_mul() {
+        let interval = Interval::new("1 mons").unwrap() * 3;
+        let expected = Interval::new("3 mons").unwrap();
+        assert_eq!(interval.pg, expected.pg);
+
+        let interval = 2 * Interval::new("1 mons 2 days 3 seconds").unwrap();
+        assert_eq!(interval.pg.months, 2);
+        assert_eq!(interval.pg.days, 4);
+        assert_eq!(interval.pg.microseconds, 6000000);
+    }
+
+    #[test]
+    fn test_interval_mul_f64() {
+        assert_eq!(Interval::new("2 hours").unwrap() * 0.5, Interval::new("1 hours").unwrap());
+        assert_eq!(Interval::new("3 days").unwrap() * 1.5, Interval::new("4 days 12 hours").unwrap());
+        assert_eq!(Interval::new("1 mons").unwrap() * 1.5, Interval::new("1 mons 15 days").unwrap());
+        assert_eq!(Interval::new("1 mons").unwrap() * 0.1, Interval::new("3 days").unwrap());
+        assert_eq!(Interval::new("-3 days").unwrap() * 0.5, Interval::new("-1 days -12 hours").unwrap());
+        assert_eq!(Interval::from_components(0, 0, 3) * 0.5, Interval::from_components(0, 0, 2));
+        assert_eq!(Interval::from_components(1, 1, 0) * 0.97, Interval::new("30 days 01:40:48").unwrap());
+        assert_eq!(Interval::from_components(-1, -1, 0) * 0.97, Interval::new("-30 days -01:40:48").unwrap());
+        assert_eq!(Interval::new("1 mons 1 days 1 seconds").unwrap() * f64::NAN, Interval::zero());
+        assert_eq!(Interval::new("1 days").unwrap() * f64::INFINITY, Interval::from_components(0, i32::MAX, 0));
+    }
+
+    #[test]
+    fn test_interval_checked_mul_f64() {
+        let interval = Interval::new("1 mons 3 days 2 hours").unwrap();
+        assert_eq!(interval.checked_mul_f64(1.5).unwrap(), interval * 1.5);
+        assert_eq!(interval.checked_mul_f64(-0.5).unwrap(), interval * -0.5);
+        let carried = Interval::from_components(1, 1, 0);
+        assert_eq!(carried.checked_mul_f64(0.97).unwrap(), carried * 0.97);
+        assert_eq!(carried.checked_mul_f64(0.97).unwrap(), Interval::new("30 days 01:40:48").unwrap());
+
+        let err = interval.checked_mul_f64(f64::NAN).unwrap_err();
+        assert_eq!(err.to_string(), "cannot scale an interval by a non-finite factor");
+        assert!(interval.checked_mul_f64(f64::NEG_INFINITY).is_err());
+
+        let err = Interval::from_days(1).checked_mul_f64(1e10).unwrap_err();
+        assert_eq!(err.to_string(), "scaled interval is out of range");
+        assert!(Interval::from_months(2).checked_mul_f64(f64::from(i32::MAX)).is_err());
+        assert!(Interval::from_seconds(1).checked_mul_f64(1e13).is_err());
+    }
+
+    #[test]
+    fn test_interval_div() {
+        assert_eq!(Interval::new("1 hours").unwrap() / 4, Interval::new("15 minutes").unwrap());
+        assert_eq!(Interval::new("1 mons").unwrap() / 2, Interval::new("15 days").unwrap());
+        assert_eq!(Interval::new("1 mons 1 days").unwrap() / 7, Interval::new("4 days 10 hours 17 minutes 8.571428 seconds").unwrap());
+        assert_eq!(Interval::from_components(0, 0, 10) / 3, Interval::from_components(0, 0, 3));
+        assert_eq!(Interval::new("-3 days").unwrap() / 2, Interval::new("-1 days -12 hours").unwrap());
+        assert_eq!(Interval::from_components(i32::MIN, 0, 0) / -1, Interval::from_components(i32::MIN, 0, 0));
+    }
+
+    #[test]
+    fn test_interval_mul_overflow() {
+        let interval = Interval {
+            pg: pg_interval::Interval {
+                months: 1,
+                days: 1,
+                microseconds: i64::MAX,
+            }
+        };
+        assert!(interval.checked_mul(2).is_none());
+        assert!(Interval::new("1 mons").unwrap().checked_mul(i32::MIN).is_some());
+        assert!(Interval::new("2 mons").unwrap().checked_mul(i32::MIN).is_none());
+
+        let interval = Interval {
+            pg: pg_interval::Interval {
+                months: 1,
+                days: 1,
+                microseconds: i64::MAX,
+            }
+        };
+        assert_eq!((interval * 2).pg.microseconds, -2);
+    }
+
+    #[test]
+    fn test_interval_sum() {
+        let intervals = vec![
+            Interval::new("1 mons 20 days 3 seconds").unwrap(),
+            Interval::new("15 days 23 hours").unwrap(),
+            Interval::new("11 mons 2 hours").unwrap(),
+        ];
+        let total: Interval = intervals.iter().sum();
+        assert_eq!(total.pg.months, 12);
+        assert_eq!(total.pg.days, 35);
+        assert_eq!(total.pg.microseconds, 25 * 3600000000 + 3000000);
+
+        let total: Interval = intervals.into_iter().sum();
+        assert_eq!(total.pg.months, 12);
+        assert_eq!(total.pg.days, 35);
+        assert_eq!(total.pg.microseconds, 25 * 3600000000 + 3000000);
+
+        let total: Interval = Vec::<Interval>::new().into_iter().sum();
+        assert_eq!(total.pg, pg_interval::Interval::new(0, 0, 0));
+    }
+
+    #[test]
+    fn test_interval_copy() {
+        let interval = Interval::new("1 mons 2 days 3 seconds").unwrap();
+        let copied = interval;
+        let total = interval + copied;
+        assert_eq!(interval.pg, copied.pg);
+        assert_eq!(interval.to_string(), "1 mon 2 days 3 seconds");
+        assert_eq!(copied.to_string(), "1 mon 2 days 3 seconds");
+        assert_eq!(total.pg.months, 2);
+    }
+
+    #[test]
+    fn test_interval_eq() {
+        assert_eq!(Interval::new("1 mons 2 days").unwrap(), Interval::new("2 days 1 mons").unwrap());
+        assert_ne!(Interval::new("1 mons").unwrap(), Interval::new("30 days").unwrap());
+        assert_ne!(Interval::new("1 days").unwrap(), Interval::new("24 hours").unwrap());
+    }
+
+    #[test]
+    fn test_interval_ord() {
+        assert!(Interval::new("1 mons").unwrap() < Interval::new("31 days").unwrap());
+        assert!(Interval::new("1 mons").unwrap() > Interval::new("29 days 23 hours").unwrap());
+        assert!(Interval::new("1 mons").unwrap() > Interval::new("30 days").unwrap());
+        assert!(Interval::new("-1 days").unwrap() < Interval::new("0 seconds").unwrap());
+
+        let mut intervals = vec![
+            Interval::new("31 days").unwrap(),
+            Interval::new("1 mons").unwrap(),
+            Interval::new("25 hours").unwrap(),
+            Interval::new("-2 days").unwrap(),
+            Interval::new("1 days").unwrap(),
+        ];
+        intervals.sort();
+        assert_eq!(intervals, vec![
+            Interval::new("-2 days").unwrap(),
+            Interval::new("1 days").unwrap(),
+            Interval::new("25 hours").unwrap(),
+            Interval::new("1 mons").unwrap(),
+            Interval::new("31 days").unwrap(),
+        ]);
+        assert_eq!(intervals.iter().max(), Some(&Interval::new("31 days").unwrap()));
+    }
+
+    #[test]
+    fn test_interval_clamp() {
+        let min = Interval::from_seconds(1);
+        let max = Interval::new("1 hours").unwrap();
+        assert_eq!(Interval::from_millis(200).clamp(min, max), min);
+        assert_eq!(Interval::new("2 hours").unwrap().clamp(min, max), max);
+        assert_eq!(Interval::new("90 seconds").unwrap().clamp(min, max), Interval::new("90 seconds").unwrap());
+        assert_eq!(Interval::from_days(45).clamp(Interval::zero(), Interval::from_months(1)), Interval::from_months(1));
+    }
+
+    #[test]
+    fn test_interval_eq_std_duration() {
+        assert_eq!(Interval::new("1 hours").unwrap(), Duration::from_secs(3600));
+        assert_eq!(Duration::from_secs(3600), Interval::new("1 hours").unwrap());
+        assert_eq!(Interval::new("1 mons 1 days").unwrap(), Duration::from_secs(31 * 86400));
+        assert_ne!(Interval::new("1 hours").unwrap(), Duration::from_secs(3601));
+        assert_ne!(Interval::from_components(0, 0, 1), Duration::from_nanos(1500));
+        assert_ne!(Interval::new("-1 hours").unwrap(), Duration::from_secs(3600));
+        assert_eq!(Interval::zero(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_interval_cmp_std_duration() {
+        let interval = Interval::new("90 seconds").unwrap();
+        assert!(interval > Duration::from_secs(60));
+        assert!(interval < Duration::from_secs(120));
+        assert!(interval <= Duration::from_secs(90) && interval >= Duration::from_secs(90));
+        assert!(Duration::from_secs(60) < interval);
+        assert!(Interval::new("1 mons").unwrap() > Duration::from_secs(29 * 86400));
+        assert!(Interval::from_components(0, 0, 1) < Duration::from_nanos(1001));
+        assert!(Interval::new("-1 seconds").unwrap() < Duration::ZERO);
+        assert!(Duration::ZERO > Interval::new("-1 seconds").unwrap());
+    }
+
+    #[test]
+    fn test_interval_hash() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(Interval::new("1 mons 2 days").unwrap());
+        set.insert(Interval::new("2 days 1 mons").unwrap());
+        assert_eq!(set.len(), 1);
+        set.insert(Interval::new("32 days").unwrap());
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_interval_default() {
+        let interval = Interval::default();
+        assert_eq!(interval.pg.months, 0);
+        assert_eq!(interval.pg.days, 0);
+        assert_eq!(interval.pg.microseconds, 0);
+        assert_eq!(interval, Interval::new("0 seconds").unwrap());
+    }
+
+    #[test]
+    fn test_interval_from_components() {
+        let interval = Interval::from_components(1, 2, 3000000);
+        assert_eq!(interval, Interval::new("1 mons 2 days 3 seconds").unwrap());
+        let interval = Interval::from_components(-14, 0, -1500000);
+        assert_eq!(interval, Interval::new("-1 years -2 mons -1.5 seconds").unwrap());
+    }
+
+    #[test]
+    fn test_interval_accessors() {
+        let interval = Interval::new("1 years 2 mons 3 days 4 seconds").unwrap();
+        assert_eq!(interval.months(), 14);
+        assert_eq!(interval.days(), 3);
+        assert_eq!(interval.microseconds(), 4000000);
+    }
+
+    #[test]
+    fn test_interval_into_inner() {
+        let interval = Interval::new("1 mons 2 days 3 seconds").unwrap();
+        assert_eq!(*interval.inner(), pg_interval::Interval::new(1, 2, 3000000));
+        let inner = interval.into_inner();
+        assert_eq!(inner, pg_interval::Interval::new(1, 2, 3000000));
+    }
+
+    #[test]
+    fn test_interval_from_pg_interval() {
+        let interval = Interval::from(pg_interval::Interval::new(1, 2, 3000000));
+        assert_eq!(interval.months(), 1);
+        assert_eq!(interval.days(), 2);
+        assert_eq!(interval.microseconds(), 3000000);
+
+        let pg: pg_interval::Interval = interval.into();
+        assert_eq!(pg.months, 1);
+        assert_eq!(pg.days, 2);
+        assert_eq!(pg.microseconds, 3000000);
+    }
+
+    #[test]
+    fn test_interval_try_from_i128() {
+        assert_eq!(Interval::try_from(3_000_000i128).unwrap(), Interval::from_seconds(3));
+        assert_eq!(Interval::try_from(i128::from(i64::MIN)).unwrap(), Interval::from_components(0, 0, i64::MIN));
+        let err = Interval::try_from(i128::from(i64::MAX) + 1).unwrap_err();
+        assert_eq!(err.to_string(), "microseconds are out of range for an interval");
+        assert!(Interval::try_from(Interval::from_months(i32::MAX).total_microseconds()).is_err());
+    }
+
+    #[test]
+    fn test_interval_from_std_duration() {
+        let interval = Interval::from(Duration::from_nanos(1_500_999));
+        assert_eq!(interval, Interval::from_components(0, 0, 1500));
+
+        let interval = Interval::from(Duration::from_secs(3 * 86400 + 2 * 3600));
+        assert_eq!(interval, Interval::from_components(0, 0, 3 * 86400000000 + 2 * 3600000000));
+        assert_eq!(interval.days(), 0);
+
+        let interval = Interval::from(Duration::MAX);
+        assert_eq!(interval, Interval::from_components(0, 0, i64::MAX));
+    }
+
+    #[test]
+    fn test_interval_checked_from_std() {
+        let interval = Interval::checked_from_std(Duration::from_millis(1500) + Duration::from_nanos(999)).unwrap();
+        assert_eq!(interval, Interval::from_components(0, 0, 1500000));
+        assert!(Interval::checked_from_std(Duration::from_micros(i64::MAX as u64)).is_ok());
+
+        let err = Interval::checked_from_std(Duration::from_secs(u64::MAX)).unwrap_err();
+        assert_eq!(err.to_string(), "std::time::Duration is out of range for an interval");
+        assert!(Interval::checked_from_std(Duration::from_micros(i64::MAX as u64 + 1)).is_err());
+    }
+
+    #[test]
+    fn test_interval_try_into_std_duration() {
+        let duration = Duration::try_from(Interval::new("1 mons 2 days 3.5 seconds").unwrap()).unwrap();
+        assert_eq!(duration, Duration::from_secs(32 * 86400 + 3) + Duration::from_millis(500));
+
+        let duration = Duration::try_from(Interval::from_components(i32::MAX, i32::MAX, i64::MAX)).unwrap();
+        assert_eq!(duration.as_secs(), (i32::MAX as u64) * 31 * 86400 + i64::MAX as u64 / 1_000_000);
+
+        let err = Duration::try_from(Interval::new("-1 days").unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "cannot convert a negative interval to a std::time::Duration");
+        assert!(Duration::try_from(Interval::new("1 days -25 hours").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_interval_as_std_duration_lossy() {
+        let interval = Interval::new("1 mons 2 days 3.5 seconds").unwrap();
+        assert_eq!(interval.as_std_duration_lossy(), Duration::try_from(interval).unwrap());
+        assert_eq!(Interval::new("-1 days").unwrap().as_std_duration_lossy(), Duration::ZERO);
+        assert_eq!(Interval::new("1 days -25 hours").unwrap().as_std_duration_lossy(), Duration::ZERO);
+        assert_eq!(Interval::from_components(i32::MAX, i32::MAX, i64::MAX).as_std_duration_lossy(), Duration::from_micros(u64::MAX));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_interval_chrono_duration_round_trip() {
+        let interval = Interval::from(chrono::Duration::nanoseconds(1_500_999));
+        assert_eq!(interval, Interval::from_components(0, 0, 1500));
+        assert_eq!(chrono::Duration::try_from(interval).unwrap(), chrono::Duration::nanoseconds(1_500_000));
+
+        let interval = Interval::from(chrono::Duration::nanoseconds(-1_500_999));
+        assert_eq!(interval, Interval::from_components(0, 0, -1500));
+        assert_eq!(chrono::Duration::try_from(interval).unwrap(), chrono::Duration::nanoseconds(-1_500_000));
+
+        let duration = chrono::Duration::try_from(Interval::new("1 mons 2 days 3 seconds").unwrap()).unwrap();
+        assert_eq!(duration, chrono::Duration::days(32) + chrono::Duration::seconds(3));
+
+        assert!(chrono::Duration::try_from(Interval::from_components(i32::MAX, 0, i64::MAX)).is_err());
+        assert_eq!(Interval::from(chrono::Duration::max_value()).microseconds(), i64::MAX);
+        assert_eq!(Interval::from(chrono::Duration::min_value()).microseconds(), i64::MIN);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_interval_add_to_datetime() {
+        let datetime = |y, m, d, h| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, 0, 0).unwrap();
+        assert_eq!(Interval::from_months(1).add_to_datetime(datetime(2024, 1, 31, 0)), datetime(2024, 2, 29, 0));
+        assert_eq!(Interval::from_months(1).add_to_datetime(datetime(2023, 1, 31, 0)), datetime(2023, 2, 28, 0));
+        assert_eq!(Interval::from_months(-1).add_to_datetime(datetime(2024, 3, 31, 0)), datetime(2024, 2, 29, 0));
+        assert_eq!(Interval::new("1 years 1 mons").unwrap().add_to_datetime(datetime(2023, 12, 15, 0)), datetime(2025, 1, 15, 0));
+
+        // Months are applied before days: Jan 31 + 1 month = Feb 29, then + 1 day = Mar 1.
+        let interval = Interval::new("1 mons 1 days 2 hours").unwrap();
+        assert_eq!(interval.add_to_datetime(datetime(2024, 1, 31, 0)), datetime(2024, 3, 1, 2));
+        assert_eq!(Interval::new("-1 days -1 hours").unwrap().add_to_datetime(datetime(2024, 3, 1, 0)), datetime(2024, 2, 28, 23));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_interval_add_calendar_months() {
+        assert_eq!(Interval::add_calendar_months(2024, 1, 31, 1), Some((2024, 2, 29)));
+        assert_eq!(Interval::add_calendar_months(2023, 1, 31, 1), Some((2023, 2, 28)));
+        assert_eq!(Interval::add_calendar_months(2100, 1, 31, 1), Some((2100, 2, 28)));
+        assert_eq!(Interval::add_calendar_months(2000, 3, 31, -1), Some((2000, 2, 29)));
+        assert_eq!(Interval::add_calendar_months(2023, 12, 15, 13), Some((2025, 1, 15)));
+        assert_eq!(Interval::add_calendar_months(2024, 1, 15, -13), Some((2022, 12, 15)));
+        assert_eq!(Interval::add_calendar_months(2024, 13, 1, 1), None);
+        assert_eq!(Interval::add_calendar_months(i32::MAX, 12, 1, 1), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_interval_subtract_from_datetime() {
+        let datetime = |y, m, d, h| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, 0, 0).unwrap();
+        assert_eq!(Interval::from_months(1).subtract_from_datetime(datetime(2024, 3, 31, 0)), datetime(2024, 2, 29, 0));
+        assert_eq!(Interval::from_months(1).subtract_from_datetime(datetime(2023, 3, 31, 0)), datetime(2023, 2, 28, 0));
+        assert_eq!(Interval::from_months(-1).subtract_from_datetime(datetime(2024, 1, 31, 0)), datetime(2024, 2, 29, 0));
+
+        // Months are applied before days: Mar 31 - 1 month = Feb 29, then - 1 day = Feb 28.
+        let interval = Interval::new("1 mons 1 days 2 hours").unwrap();
+        assert_eq!(interval.subtract_from_datetime(datetime(2024, 3, 31, 3)), datetime(2024, 2, 28, 1));
+        assert_eq!(Interval::new("-1 days -1 hours").unwrap().subtract_from_datetime(datetime(2024, 2, 28, 23)), datetime(2024, 3, 1, 0));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_interval_time_duration_round_trip() {
+        // time::Duration has nanosecond precision, so the sub-microsecond part is lost.
+        let interval = Interval::from(time::Duration::nanoseconds(1_500_999));
+        assert_eq!(interval, Interval::from_components(0, 0, 1500));
+        assert_eq!(time::Duration::try_from(interval).unwrap(), time::Duration::nanoseconds(1_500_000));
+
+        let interval = Interval::from(time::Duration::nanoseconds(-1_500_999));
+        assert_eq!(interval, Interval::from_components(0, 0, -1500));
+        assert_eq!(time::Duration::try_from(interval).unwrap(), time::Duration::nanoseconds(-1_500_000));
+
+        let duration = time::Duration::try_from(Interval::new("1 mons 2 days 3 seconds").unwrap()).unwrap();
+        assert_eq!(duration, time::Duration::days(32) + time::Duration::seconds(3));
+
+        let duration = time::Duration::try_from(Interval::from_components(i32::MAX, i32::MAX, i64::MAX)).unwrap();
+        assert_eq!(duration.whole_microseconds(), Interval::from_components(i32::MAX, i32::MAX, i64::MAX).total_microseconds());
+        assert_eq!(Interval::from(time::Duration::MAX).microseconds(), i64::MAX);
+        assert_eq!(Interval::from(time::Duration::MIN).microseconds(), i64::MIN);
+    }
+
+    #[test]
+    fn test_interval_total_microseconds() {
+        assert_eq!(Interval::new("1 mons").unwrap().total_microseconds(), 2_592_000_000_000);
+        assert_eq!(Interval::new("1 days 3 seconds").unwrap().total_microseconds(), 86_403_000_000);
+        assert_eq!(Interval::new("1 mons -30 days").unwrap().total_microseconds(), 0);
+        assert_eq!(
+            Interval::from_components(i32::MAX, i32::MAX, i64::MAX).total_microseconds(),
+            i128::from(i32::MAX) * 31 * 86_400_000_000 + i128::from(i64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_interval_approx_eq() {
+        let interval = Interval::new("1 hours").unwrap();
+        assert!(interval.approx_eq(&(interval + Interval::from_components(0, 0, 1)), Duration::from_millis(1)));
+        assert!(interval.approx_eq(&(interval - Interval::from_components(0, 0, 1)), Duration::from_millis(1)));
+        assert!(!interval.approx_eq(&(interval + Interval::from_seconds(2)), Duration::from_secs(1)));
+        assert!(interval.approx_eq(&interval, Duration::ZERO));
+        assert!(Interval::from_months(1).approx_eq(&Interval::from_days(30), Duration::ZERO));
+    }
+
+    #[test]
+    fn test_interval_signum() {
+        let positive = Interval::new("1 days").unwrap();
+        assert!(!positive.is_negative());
+        assert_eq!(positive.signum(), 1);
+
+        let negative = Interval::new("-3 seconds").unwrap();
+        assert!(negative.is_negative());
+        assert_eq!(negative.signum(), -1);
+
+        assert!(!Interval::zero().is_negative());
+        assert_eq!(Interval::zero().signum(), 0);
+        assert_eq!(Interval::new("1 days -24 hours").unwrap().signum(), 0);
+
+        let mixed = Interval::from_components(1, -31, 0);
+        assert!(mixed.is_negative());
+        assert_eq!(mixed.signum(), -1);
+        assert_eq!(Interval::from_components(-1, 30, 1).signum(), 1);
+    }
+
+    #[test]
+    fn test_interval_as_whole_units() {
+        let interval = Interval::new("90 minutes").unwrap();
+        assert_eq!(interval.as_whole_minutes(), 90);
+        assert_eq!(interval.as_whole_hours(), 1);
+        assert_eq!(interval.as_whole_days(), 0);
+        assert_eq!(Interval::new("25 hours").unwrap().as_whole_days(), 1);
+        assert_eq!(Interval::new("1 mons 1 days").unwrap().as_whole_days(), 31);
+        assert_eq!(Interval::new("-90 seconds").unwrap().as_whole_minutes(), -1);
+        assert_eq!(Interval::new("-25 hours").unwrap().as_whole_days(), -1);
+    }
+
+    #[test]
+    fn test_interval_as_seconds_f64() {
+        assert_eq!(Interval::new("1 hours 30 minutes").unwrap().as_seconds_f64(), 5400.0);
+        assert_eq!(Interval::new("1 days -0.25 seconds").unwrap().as_seconds_f64(), 86399.75);
+        assert_eq!(Interval::new("-1 mons").unwrap().as_seconds_f64(), -2592000.0);
+    }
+
+    #[test]
+    fn test_interval_epoch_seconds() {
+        assert_eq!(Interval::new("1 hours").unwrap().epoch_seconds(), 3600.0);
+        assert_eq!(Interval::from_epoch_seconds(3600.0), Interval::new("1 hours").unwrap());
+        assert_eq!(Interval::new("1.25 seconds").unwrap().epoch_seconds(), 1.25);
+        assert_eq!(Interval::from_epoch_seconds(1.2500004), Interval::from_components(0, 0, 1_250_000));
+        assert_eq!(Interval::from_epoch_seconds(-0.0000015), Interval::from_components(0, 0, -2));
+        assert_eq!(Interval::new("1 years 1 mons 1 days").unwrap().epoch_seconds(), 31557600.0 + 2592000.0 + 86400.0);
+        assert_eq!(Interval::from_epoch_seconds(f64::NAN), Interval::zero());
+    }
+
+    #[test]
+    fn test_interval_from_iso8601() {
+        let interval = Interval::from_iso8601("P1Y2M3DT4H5M6.5S").unwrap();
+        assert_eq!(interval, Interval::from_components(14, 3, 4 * 3600000000 + 5 * 60000000 + 6500000));
+
+        let interval = Interval::from_iso8601("PT90M").unwrap();
+        assert_eq!(interval, Interval::from_components(0, 0, 90 * 60000000));
+
+        let interval = Interval::from_iso8601("P2M").unwrap();
+        assert_eq!(interval, Interval::from_components(2, 0, 0));
+
+        assert_eq!(Interval::from_iso8601("1Y2M").unwrap_err().to_string(), "Invalid format must start with P.");
+        assert!(Interval::from_iso8601("P1X").is_err());
+        assert!(Interval::from_iso8601("P1S").is_err());
+    }
+
+    #[test]
+    fn test_interval_from_iso8601_weeks() {
+        assert_eq!(Interval::from_iso8601("P2W").unwrap(), Interval::from_days(14));
+        assert_eq!(Interval::from_iso8601("P0W").unwrap(), Interval::zero());
+        let err = Interval::from_iso8601("P1Y2W").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidInterval);
+        assert_eq!(err.to_string(), "Week durations must be a whole number of weeks with no other components.");
+        assert!(Interval::from_iso8601("P1.5W").is_err());
+        assert!(Interval::from_iso8601("PT2W").is_err());
+        assert!(Interval::from_iso8601("PW").is_err());
+        assert!(Interval::from_iso8601("P2W1D").is_err());
+    }
+
+    #[test]
+    fn test_interval_to_iso8601() {
+        let interval = Interval::from_components(14, 3, 4 * 3600000000 + 5 * 60000000 + 6500000);
+        assert_eq!(interval.to_iso8601(), "P1Y2M3DT4H5M6.5S");
+        assert_eq!(Interval::from_iso8601(&interval.to_iso8601()).unwrap(), interval);
+
+        let interval = Interval::from_components(0, 1, -250);
+        assert_eq!(interval.to_iso8601(), "P1DT-0.00025S");
+        assert_eq!(Interval::from_iso8601(&interval.to_iso8601()).unwrap(), interval);
+
+        let interval = Interval::from_components(-13, 0, -90 * 60000000);
+        assert_eq!(interval.to_iso8601(), "P-1Y-1MT-1H-30M");
+        assert_eq!(Interval::from_iso8601(&interval.to_iso8601()).unwrap(), interval);
+
+        assert_eq!(Interval::default().to_iso8601(), "PT0S");
+        assert_eq!(Interval::from_iso8601("PT0S").unwrap(), Interval::default());
+    }
+
+    #[test]
+    fn test_interval_to_postgres_string() {
+        let interval = Interval::from_components(14, 3, 4 * 3600000000 + 5 * 60000000 + 6000000);
+        assert_eq!(interval.to_postgres_string(), "1 year 2 mons 3 days 04:05:06");
+        assert_eq!(Interval::from_components(1, 1, 0).to_postgres_string(), "1 mon 1 day");
+        assert_eq!(Interval::from_components(-14, 0, 0).to_postgres_string(), "-1 years -2 mons");
+        assert_eq!(Interval::from_components(0, -1, 2 * 3600000000).to_postgres_string(), "-1 days +02:00:00");
+        assert_eq!(Interval::from_components(0, 1, -1500000).to_postgres_string(), "1 day -00:00:01.5");
+        assert_eq!(Interval::from_components(0, 0, 25 * 3600000000 + 123).to_postgres_string(), "25:00:00.000123");
+        assert_eq!(Interval::default().to_postgres_string(), "00:00:00");
+    }
+
+    #[test]
+    fn test_interval_new_clock() {
+        assert_eq!(Interval::new("04:05:06.5").unwrap(), Interval::from_components(0, 0, 4 * 3600000000 + 5 * 60000000 + 6500000));
+        assert_eq!(Interval::new("04:05:06.123456").unwrap(), Interval::from_components(0, 0, 4 * 3600000000 + 5 * 60000000 + 6123456));
+        assert_eq!(Interval::new("04:05").unwrap(), Interval::from_components(0, 0, 4 * 3600000000 + 5 * 60000000));
+        assert_eq!(Interval::new("3 days 04:05").unwrap(), Interval::from_components(0, 3, 4 * 3600000000 + 5 * 60000000));
+        assert_eq!(Interval::new("-04:05:06").unwrap(), Interval::from_components(0, 0, -(4 * 3600000000 + 5 * 60000000 + 6000000)));
+        assert_eq!(Interval::new("1 days 2 hours 00:30").unwrap(), Interval::from_components(0, 1, 2 * 3600000000 + 30 * 60000000));
+        let interval = Interval::from_components(26, -2, 2 * 3600000000 + 123);
+        assert_eq!(Interval::new(&interval.to_postgres_string()).unwrap(), interval);
+        assert_eq!(Interval::new("04:60").unwrap_err().kind(), ParseErrorKind::InvalidTime);
+        assert_eq!(Interval::new("00:00:60").unwrap_err().kind(), ParseErrorKind::InvalidTime);
+        assert_eq!(Interval::new("3000000000:00").unwrap_err().kind(), ParseErrorKind::InvalidTime);
+        assert_eq!(Interval::new("99999999999999999999999:00").unwrap_err().kind(), ParseErrorKind::InvalidTime);
+        assert_eq!(Interval::new("2562047788 hours 01:00").unwrap_err().kind(), ParseErrorKind::InvalidTime);
+        assert!(Interval::new("04:05:06:07").is_err());
+        assert!(Interval::new("04:05 06:07").is_err());
+        assert!(Interval::new("1 days :05").is_err());
+    }
+
+    #[test]
+    fn test_interval_write_postgres() {
+        let mut buf = String::with_capacity(64);
+        Interval::from_components(14, 3, 4 * 3600000000 + 5 * 60000000 + 6000000).write_postgres(&mut buf).unwrap();
+        assert_eq!(buf, "1 year 2 mons 3 days 04:05:06");
+        let capacity = buf.capacity();
+        buf.clear();
+        Interval::from_components(0, -1, 2 * 3600000000 + 500000).write_postgres(&mut buf).unwrap();
+        assert_eq!(buf, "-1 days +02:00:00.5");
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_interval_format_with_precision() {
+        let interval = Interval::from_components(0, 1, 4 * 3600000000 + 5 * 60000000 + 6 * 1000000 + 123456);
+        assert_eq!(interval.format_with_precision(0), "1 day 04:05:06");
+        assert_eq!(interval.format_with_precision(3), "1 day 04:05:06.123");
+        assert_eq!(interval.format_with_precision(6), "1 day 04:05:06.123456");
+        assert_eq!(interval.format_with_precision(9), "1 day 04:05:06.123456");
+
+        let interval = Interval::from_components(0, 0, 59 * 1000000 + 999600);
+        assert_eq!(interval.format_with_precision(0), "00:01:00");
+        assert_eq!(interval.format_with_precision(3), "00:01:00.000");
+        assert_eq!(Interval::from_components(0, 0, -1500000).format_with_precision(3), "-00:00:01.500");
+        assert_eq!(Interval::from_months(14).format_with_precision(2), "1 year 2 mons 00:00:00.00");
+    }
+
+    #[test]
+    fn test_interval_humanize() {
+        assert_eq!(Interval::new("1 days 20 hours").unwrap().humanize(), "2 days");
+        assert_eq!(Interval::new("1 days 11 hours").unwrap().humanize(), "1 day");
+        assert_eq!(Interval::new("23 hours 40 minutes").unwrap().humanize(), "1 day");
+        assert_eq!(Interval::new("2 hours 29 minutes").unwrap().humanize(), "2 hours");
+        assert_eq!(Interval::new("2 hours 30 minutes").unwrap().humanize(), "3 hours");
+        assert_eq!(Interval::new("1 years 5 mons").unwrap().humanize(), "1 year");
+        assert_eq!(Interval::new("11 mons 20 days").unwrap().humanize(), "1 year");
+        assert_eq!(Interval::new("45 days").unwrap().humanize(), "2 months");
+        assert_eq!(Interval::new("90 seconds").unwrap().humanize(), "2 minutes");
+        assert_eq!(Interval::new("-3 days").unwrap().humanize(), "-3 days");
+        assert_eq!(Interval::from_millis(400).humanize(), "0 seconds");
+        assert_eq!(Interval::from_millis(-400).humanize(), "0 seconds");
+        assert_eq!(Interval::zero().humanize(), "0 seconds");
+    }
+
+    #[test]
+    fn test_interval_zero() {
+        assert_eq!(Interval::zero(), Interval::default());
+        assert!(Interval::zero().is_zero());
+        assert!(Interval::from_components(0, 0, 0).is_zero());
+        assert!(!Interval::new("1 days -24 hours").unwrap().is_zero());
+        assert!(!Interval::from_components(1, 0, 0).is_zero());
+        assert!(!Interval::from_components(0, -1, 0).is_zero());
+        assert!(!Interval::from_components(0, 0, 1).is_zero());
+    }
+
+    #[test]
+    fn test_interval_min_max() {
+        assert_eq!(Interval::MAX.total_microseconds(), i128::from(i32::MAX) * 31 * 86_400_000_000 + i128::from(i64::MAX));
+        assert_eq!(Interval::MIN.total_microseconds(), i128::from(i32::MIN) * 31 * 86_400_000_000 + i128::from(i64::MIN));
+        assert_eq!(Interval::new("1 mons -2 days 3 seconds").unwrap().saturating_add(Interval::MAX), Interval::from_components(i32::MAX, i32::MAX - 2, i64::MAX));
+        assert_eq!(Interval::MAX.saturating_add(Interval::from_seconds(1)), Interval::MAX);
+        assert_eq!(Interval::MIN.saturating_sub(Interval::from_components(1, 1, 1)), Interval::MIN);
+        assert_eq!(Interval::new("1 years").unwrap().clamp(Interval::MIN, Interval::MAX), Interval::new("1 years").unwrap());
+        assert!(Interval::MIN < Interval::ZERO && Interval::ZERO < Interval::MAX);
+    }
+
+    #[test]
+    fn test_interval_is_time_only_and_date_only() {
+        assert!(Interval::new("4 hours 5 seconds").unwrap().is_time_only());
+        assert!(!Interval::new("4 hours 5 seconds").unwrap().is_date_only());
+        assert!(Interval::new("1 mons 2 days").unwrap().is_date_only());
+        assert!(!Interval::new("1 mons 2 days").unwrap().is_time_only());
+        assert!(!Interval::new("1 days 1 seconds").unwrap().is_time_only());
+        assert!(!Interval::new("1 days 1 seconds").unwrap().is_date_only());
+        assert!(Interval::zero().is_time_only() && Interval::zero().is_date_only());
+    }
+
+    #[test]
+    fn test_interval_const() {
+        const ZERO: Interval = Interval::ZERO;
+        const TIMEOUT: Interval = Interval::from_seconds(30);
+        static STEPS: [Interval; 3] = [Interval::ZERO, Interval::from_days(1), Interval::from_components(1, 2, 3)];
+        assert_eq!(ZERO, Interval::zero());
+        assert_eq!(TIMEOUT, Interval::new("30 seconds").unwrap());
+        assert_eq!(STEPS[2], Interval::new("1 mons 2 days 0.000003 seconds").unwrap());
+        assert!(STEPS[0].is_zero());
+    }
+
+    #[test]
+    fn test_interval_lerp() {
+        let a = Interval::zero();
+        let b = Interval::new("1 hours").unwrap();
+        assert_eq!(Interval::lerp(a, b, 0.0), a);
+        assert_eq!(Interval::lerp(a, b, 1.0), b);
+        assert_eq!(Interval::lerp(a, b, 0.5), Interval::new("30 minutes").unwrap());
+        assert_eq!(Interval::lerp(a, b, 2.0), b);
+        assert_eq!(Interval::lerp(a, b, -1.0), a);
+        assert_eq!(Interval::lerp_unclamped(a, b, 2.0), Interval::new("2 hours").unwrap());
+        assert_eq!(Interval::lerp_unclamped(a, b, -0.5), Interval::new("-30 minutes").unwrap());
+        assert_eq!(Interval::lerp(Interval::from_months(1), Interval::from_months(2), 0.5), Interval::from_days(45).normalize_sign());
+        let a = Interval::from_days(1);
+        assert_eq!(Interval::lerp_unclamped(a, Interval::MAX, f64::INFINITY), Interval::from_total_microseconds(i128::MAX));
+        assert_eq!(Interval::lerp_unclamped(a, Interval::MAX, f64::NEG_INFINITY), Interval::from_total_microseconds(i128::MIN + a.total_microseconds()));
+        assert_eq!(Interval::lerp_unclamped(-a, Interval::MIN, 1e300), Interval::from_total_microseconds(i128::MIN));
+        assert_eq!(Interval::lerp_unclamped(a, Interval::MAX, f64::NAN), a);
+    }
+
+    #[test]
+    fn test_interval_abs() {
+        assert_eq!(Interval::new("-3 days").unwrap().abs(), Interval::new("3 days").unwrap());
+        assert_eq!(Interval::new("-1 mons 2 days -3 seconds").unwrap().abs(), Interval::new("1 mons 2 days 3 seconds").unwrap());
+        assert_eq!(Interval::from_components(i32::MIN, i32::MIN, i64::MIN).abs(), Interval::from_components(i32::MAX, i32::MAX, i64::MAX));
+    }
+
+    #[test]
+    fn test_interval_round_to_seconds() {
+        let interval = Interval::new("3.7 seconds").unwrap();
+        assert_eq!(interval.round_to_seconds(), Interval::new("4 seconds").unwrap());
+        assert_eq!(interval.truncate_to_seconds(), Interval::new("3 seconds").unwrap());
+        assert_eq!(Interval::new("2.5 seconds").unwrap().round_to_seconds(), Interval::new("3 seconds").unwrap());
+        assert_eq!(Interval::new("-2.5 seconds").unwrap().round_to_seconds(), Interval::new("-3 seconds").unwrap());
+        assert_eq!(Interval::new("-3.7 seconds").unwrap().truncate_to_seconds(), Interval::new("-3 seconds").unwrap());
+        assert_eq!(Interval::new("1 mons 2 days 3.2 seconds").unwrap().round_to_seconds(), Interval::new("1 mons 2 days 3 seconds").unwrap());
+    }
+
+    #[test]
+    fn test_interval_round_to() {
+        let interval = Interval::new("1 hours 31 minutes").unwrap();
+        assert_eq!(interval.round_to(RoundUnit::Hour), Interval::new("2 hours").unwrap());
+        assert_eq!(interval.round_to(RoundUnit::Day), Interval::zero());
+        assert_eq!((-interval).round_to(RoundUnit::Hour), Interval::new("-2 hours").unwrap());
+        assert_eq!(interval.round_to(RoundUnit::Microsecond), interval);
+
+        let interval = Interval::new("1 mons 2 days 13 hours").unwrap();
+        assert_eq!(interval.round_to(RoundUnit::Day), Interval::from_components(1, 2, 86_400_000_000));
+        assert_eq!(Interval::new("1.2345 seconds").unwrap().round_to(RoundUnit::Millisecond), Interval::new("1.235 seconds").unwrap());
+        assert_eq!(Interval::new("90.5 seconds").unwrap().round_to_minutes(), Interval::new("2 minutes").unwrap());
+        assert_eq!(Interval::new("89 seconds").unwrap().round_to_minutes(), Interval::new("1 minutes").unwrap());
+    }
+
+    #[test]
+    fn test_interval_split_time_of_day() {
+        let interval = Interval::new("1 years 2 mons -3 days 4 hours 5.5 seconds").unwrap();
+        let (date, time) = interval.split_time_of_day();
+        assert_eq!(date, Interval::new("1 years 2 mons -3 days").unwrap());
+        assert_eq!(time, Interval::new("4 hours 5.5 seconds").unwrap());
+        assert_eq!(date + time, interval);
+        assert_eq!(Interval::zero().split_time_of_day(), (Interval::zero(), Interval::zero()));
+    }
+
+    #[test]
+    fn test_interval_restrict_to() {
+        let interval = Interval::new("1 years 2 mons 3 days 4 hours 5 minutes 6.5 seconds").unwrap();
+        assert_eq!(interval.restrict_to(IntervalFields::YearToMonth), Interval::new("1 years 2 mons").unwrap());
+        assert_eq!(interval.restrict_to(IntervalFields::DayToSecond), Interval::new("3 days 4 hours 5 minutes 6.5 seconds").unwrap());
+        assert_eq!(interval.restrict_to(IntervalFields::Year), Interval::new("1 years").unwrap());
+        assert_eq!(interval.restrict_to(IntervalFields::Month), Interval::new("2 mons").unwrap());
+        assert_eq!(interval.restrict_to(IntervalFields::Day), Interval::new("3 days").unwrap());
+        assert_eq!(interval.restrict_to(IntervalFields::DayToHour), Interval::new("3 days 4 hours").unwrap());
+        assert_eq!(interval.restrict_to(IntervalFields::HourToMinute), Interval::new("4 hours 5 minutes").unwrap());
+        assert_eq!(interval.restrict_to(IntervalFields::MinuteToSecond), Interval::new("5 minutes 6.5 seconds").unwrap());
+        assert_eq!(interval.restrict_to(IntervalFields::Second), Interval::new("6.5 seconds").unwrap());
+        assert_eq!((-interval).restrict_to(IntervalFields::DayToMinute), Interval::new("-3 days -4 hours -5 minutes").unwrap());
+    }
+
+    #[test]
+    fn test_interval_justify_interval() {
+        let interval = Interval::from_components(1, 32, 25 * 3600000000);
+        assert_eq!(interval.justify_interval().unwrap().to_postgres_string(), "2 mons 3 days 01:00:00");
+
+        let interval = Interval::from_components(1, 0, -3600000000);
+        assert_eq!(interval.justify_interval().unwrap().to_postgres_string(), "29 days 23:00:00");
+
+        let interval = Interval::from_components(-1, 0, 3600000000);
+        assert_eq!(interval.justify_interval().unwrap().to_postgres_string(), "-29 days -23:00:00");
+
+        let interval = Interval::from_components(0, -35, -49 * 3600000000);
+        assert_eq!(interval.justify_interval().unwrap().to_postgres_string(), "-1 mons -7 days -01:00:00");
+
+        assert_eq!(Interval::zero().justify_interval(), Some(Interval::zero()));
+
+        assert_eq!(Interval::from_components(i32::MAX, 30, 0).justify_interval(), None);
+        assert_eq!(Interval::from_components(i32::MIN, -30, 0).justify_interval(), None);
+        let interval = Interval::from_components(0, i32::MAX, 86_400_000_000).justify_interval();
+        assert_eq!(interval, Some(Interval::from_components(71582788, 8, 0)));
+    }
+
+    #[test]
+    fn test_interval_justify_hours() {
+        let interval = Interval::from_components(0, 0, 49 * 3600000000);
+        assert_eq!(interval.justify_hours().unwrap().to_postgres_string(), "2 days 01:00:00");
+
+        let interval = Interval::from_components(1, 1, -3600000000);
+        assert_eq!(interval.justify_hours().unwrap().to_postgres_string(), "1 mon 23:00:00");
+
+        let interval = Interval::from_components(0, 35, 0);
+        assert_eq!(interval.justify_hours(), Some(interval));
+
+        assert_eq!(Interval::from_components(0, i32::MAX, 86_400_000_000).justify_hours(), None);
+        assert_eq!(Interval::from_components(0, i32::MIN, -86_400_000_000).justify_hours(), None);
+        let interval = Interval::from_components(0, i32::MAX, 86_400_000_000 - 1);
+        assert_eq!(interval.justify_hours(), Some(interval));
+    }
+
+    #[test]
+    fn test_interval_justify_days() {
+        let interval = Interval::from_components(0, 35, 0);
+        assert_eq!(interval.justify_days().unwrap().to_postgres_string(), "1 mon 5 days");
+
+        let interval = Interval::from_components(1, -1, 0);
+        assert_eq!(interval.justify_days().unwrap().to_postgres_string(), "29 days");
+
+        assert_eq!(Interval::from_components(i32::MAX, 30, 0).justify_days(), None);
+        assert_eq!(Interval::from_components(i32::MAX, 29, 0).justify_days(), Some(Interval::from_components(i32::MAX, 29, 0)));
+
+        let interval = Interval::from_components(0, 0, 49 * 3600000000);
+        assert_eq!(interval.justify_days(), Some(interval));
+    }
+
+    #[test]
+    fn test_interval_normalize_sign() {
+        assert_eq!(Interval::from_components(1, -5, 0).normalize_sign(), Interval::from_days(25));
+        assert_eq!(Interval::new("1 days -25 hours").unwrap().normalize_sign(), Interval::new("-1 hours").unwrap());
+        assert_eq!(
+            Interval::new("-1 mons 2 days -3 hours").unwrap().normalize_sign(),
+            Interval::new("-28 days -3 hours").unwrap()
+        );
+        assert_eq!(Interval::from_components(0, 31, 25 * 3600000000).normalize_sign(), Interval::new("1 mons 2 days 1 hours").unwrap());
+        assert_eq!(Interval::new("1 mons 30 days").unwrap().normalize_sign(), Interval::from_months(2));
+    }
+
+    #[test]
+    fn test_interval_iso_serde() {
+        let interval = IntervalIso(Interval::from_components(14, 3, 4 * 3600000000 + 500000));
+        let serialized = serde_json::to_string(&interval).unwrap();
+        assert_eq!(serialized, r#""P1Y2M3DT4H0.5S""#);
+        let deserialized: IntervalIso = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, interval);
+
+        let deserialized: IntervalIso = serde_json::from_str(r#""PT90M""#).unwrap();
+        assert_eq!(Interval::from(deserialized), Interval::from_components(0, 0, 90 * 60000000));
+
+        assert!(serde_json::from_str::<IntervalIso>(r#""1 mons""#).is_err());
+        assert!(serde_json::from_str::<IntervalIso>(r#"{"m":1,"d":2,"us":3}"#).is_err());
+    }
+
+    #[test]
+    fn test_interval_iso_from_str() {
+        let interval = "P1Y2M3DT4H".parse::<Iso8601Interval>().unwrap();
+        assert_eq!(interval, IntervalIso(Interval::from_components(14, 3, 4 * 3600000000)));
+        assert!("1 years 2 mons 3 days 4 hours".parse::<Iso8601Interval>().is_err());
+        assert!("P1Y2M3DT4H".parse::<Interval>().is_err());
+    }
+
+    #[test]
+    #[cfg(not(feature = "serde-iso"))]
+    fn test_interval_deserialize_str() {
+        let from_map: Interval = serde_json::from_str(r#"{"m":1,"d":2,"us":0}"#).unwrap();
+        let from_str: Interval = serde_json::from_str(r#""1 mon 2 days""#).unwrap();
+        assert_eq!(from_map, from_str);
+        let from_str: Interval = serde_json::from_str(r#""1 mons 2 days 3 seconds""#).unwrap();
+        assert_eq!(from_str, Interval::from_components(1, 2, 3000000));
+
+        let err = serde_json::from_str::<Interval>(r#""1 monthss""#).unwrap_err();
+        assert_eq!(err.to_string(), "Unknown or duplicate deliminator \"monthss\" at line 1 column 11");
+        assert!(serde_json::from_str::<Interval>("1.5").is_err());
+    }
+
+    #[test]
+    fn test_interval_deserialize_microseconds() {
+        let interval: Interval = serde_json::from_str("3000000").unwrap();
+        assert_eq!(interval, Interval::from_seconds(3));
+        let interval: Interval = serde_json::from_str("-3000000").unwrap();
+        assert_eq!(interval, Interval::from_seconds(-3));
+        assert!(serde_json::from_str::<Interval>("9223372036854775808").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde-iso")]
+    fn test_interval_serde_iso() {
+        let interval = Interval::from_components(14, 3, 4 * 3600000000);
+        let serialized = serde_json::to_string(&interval).unwrap();
+        assert_eq!(serialized, r#""P1Y2M3DT4H""#);
+        assert_eq!(serde_json::from_str::<Interval>(&serialized).unwrap(), interval);
+        assert_eq!(serde_json::from_str::<Interval>(r#"{"m":14,"d":3,"us":14400000000}"#).unwrap(), interval);
+        assert!(serde_json::from_str::<Interval>(r#""1 years 2 mons""#).is_err());
+        assert_eq!(interval.to_jsonb_bytes(), br#""P1Y2M3DT4H""#);
+        assert_eq!(bincode::deserialize::<Interval>(&bincode::serialize(&interval).unwrap()).unwrap(), interval);
+    }
+
+    #[test]
+    fn test_interval_bincode() {
+        let interval = Interval::from_components(1, 2, 3);
+        let serialized = bincode::serialize(&interval).unwrap();
+        assert_eq!(serialized, vec![1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0]);
+        let deserialized: Interval = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, interval);
+        #[cfg(not(feature = "serde-iso"))]
+        assert_eq!(serde_json::to_string(&interval).unwrap(), r#"{"m":1,"d":2,"us":3}"#);
+    }
+
+    #[test]
+    fn test_interval_as_total_microseconds() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Row {
+            #[serde(with = "as_total_microseconds")]
+            interval: Interval,
+        }
+
+        let row = Row { interval: Interval::new("2 days 3 seconds").unwrap() };
+        let serialized = serde_json::to_string(&row).unwrap();
+        assert_eq!(serialized, r#"{"interval":"172803000000"}"#);
+        let deserialized: Row = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.interval, Interval::from_components(0, 0, 172803000000));
+        assert_eq!(deserialized.interval.total_microseconds(), row.interval.total_microseconds());
+
+        let deserialized: Row = serde_json::from_str(r#"{"interval":3000000}"#).unwrap();
+        assert_eq!(deserialized.interval, Interval::from_seconds(3));
+
+        let row = Row { interval: Interval::from_months(-1) };
+        let deserialized: Row = bincode::deserialize(&bincode::serialize(&row).unwrap()).unwrap();
+        assert_eq!(deserialized.interval, Interval::new("-720 hours").unwrap());
+
+        assert!(serde_json::from_str::<Row>(r#"{"interval":"99999999999999999999"}"#).is_err());
+    }
+
+    #[test]
+    fn test_interval_parts_serde() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Row {
+            retry_after: IntervalParts,
+        }
+
+        let interval = Interval::new("1 mons 2 days 3 seconds").unwrap();
+        let row = Row { retry_after: interval.into() };
+        let serialized = serde_json::to_string(&row).unwrap();
+        assert_eq!(serialized, r#"{"retryAfter":{"months":1,"days":2,"microseconds":3000000}}"#);
+        let deserialized: Row = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(Interval::from(deserialized.retry_after), interval);
+    }
+
+    #[test]
+    fn test_interval_verbose_serde() {
+        let interval = IntervalVerbose(Interval::from_components(1, 2, 3));
+        let serialized = serde_json::to_string(&interval).unwrap();
+        assert_eq!(serialized, r#"{"months":1,"days":2,"microseconds":3}"#);
+        let deserialized: IntervalVerbose = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, interval);
+
+        assert!(serde_json::from_str::<IntervalVerbose>(r#"{"m":1,"d":2,"us":3}"#).is_err());
+        assert!(serde_json::from_str::<IntervalVerbose>(r#"{"months":1,"days":2}"#).is_err());
+        #[cfg(not(feature = "serde-iso"))]
+        assert_eq!(serde_json::to_string(&interval.0).unwrap(), r#"{"m":1,"d":2,"us":3}"#);
+    }
+
+    #[test]
+    fn test_interval_from_months_and_days() {
+        let interval = Interval::from_months(14);
+        assert_eq!((interval.months(), interval.days(), interval.microseconds()), (14, 0, 0));
+        assert_eq!(interval, Interval::new("1 years 2 mons").unwrap());
+
+        let interval = Interval::from_days(90);
+        assert_eq!((interval.months(), interval.days(), interval.microseconds()), (0, 90, 0));
+        assert_eq!(interval, Interval::new("90 days").unwrap());
+    }
+
+    #[test]
+    fn test_interval_from_seconds() {
+        assert_eq!(Interval::from_seconds(90), Interval::new("1 minutes 30 seconds").unwrap());
+        assert_eq!(Interval::from_seconds(-3), Interval::new("-3 seconds").unwrap());
+        assert_eq!(Interval::from_seconds(i64::MAX / 1_000_000).microseconds(), i64::MAX / 1_000_000 * 1_000_000);
+        assert_eq!(Interval::from_seconds(i64::MAX / 1_000_000 + 1).microseconds(), i64::MAX);
+        assert_eq!(Interval::from_seconds(i64::MIN).microseconds(), i64::MIN);
+    }
+
+    #[test]
+    fn test_interval_from_hms() {
+        assert_eq!(Interval::from_hms(1, 30, 0), Some(Interval::new("1 hours 30 minutes").unwrap()));
+        assert_eq!(Interval::from_hms(-1, 0, 30), Some(Interval::new("-59 minutes -30 seconds").unwrap()));
+        assert_eq!(Interval::from_hms(0, 0, 0), Some(Interval::zero()));
+        assert_eq!(Interval::from_hms(3_000_000_000, 0, 0), None);
+        assert_eq!(Interval::from_hms(2_562_047_788, 0, 55), None);
+        assert!(Interval::from_hms(2_562_047_788, 0, 54).is_some());
+        assert_eq!(Interval::from_hms(0, i64::MAX, 0), None);
+    }
+
+    #[test]
+    fn test_interval_from_millis() {
+        assert_eq!(Interval::from_millis(1500), Interval::new("1.5 seconds").unwrap());
+        assert_eq!(Interval::from_millis(-250), Interval::from_components(0, 0, -250000));
+        assert_eq!(Interval::from_millis(i64::MAX / 1_000 + 1).microseconds(), i64::MAX);
+    }
+
+    #[test]
+    fn test_interval_builder() {
+        let interval = Interval::builder().years(1).months(2).days(3).hours(4).build();
+        assert_eq!(interval, Interval::new("1 years 2 mons 3 days 4 hours").unwrap());
+
+        let interval = IntervalBuilder::new()
+            .minutes(90)
+            .seconds(1)
+            .milliseconds(500)
+            .microseconds(-1)
+            .days(1)
+            .days(1)
+            .build();
+        assert_eq!(interval, Interval::from_components(0, 2, 90 * 60000000 + 1499999));
+        assert_eq!(IntervalBuilder::new().build(), Interval::zero());
+    }
+
+    #[test]
+    fn test_interval_builder_try_build() {
+        let interval = Interval::builder().years(1).months(2).days(3).hours(4).try_build().unwrap();
+        assert_eq!(interval, Interval::new("1 years 2 mons 3 days 4 hours").unwrap());
+
+        let err = Interval::builder().years(i32::MAX).months(1).try_build().unwrap_err();
+        assert_eq!(err.unit(), BuildErrorUnit::Years);
+        assert_eq!(err.to_string(), "interval years out of range");
+        let err = Interval::builder().years(178956970).months(i32::MAX).try_build().unwrap_err();
+        assert_eq!(err.unit(), BuildErrorUnit::Months);
+        assert_eq!(err.to_string(), "interval months out of range");
+        assert_eq!(Interval::builder().days(i32::MAX).days(1).try_build().unwrap_err().unit(), BuildErrorUnit::Days);
+        assert_eq!(Interval::builder().hours(i64::MAX).try_build().unwrap_err().unit(), BuildErrorUnit::Hours);
+        assert_eq!(Interval::builder().hours(-1).microseconds(i64::MIN).try_build().unwrap_err().unit(), BuildErrorUnit::Microseconds);
+        assert_eq!(Interval::builder().hours(1).milliseconds(i64::MAX).try_build().unwrap_err().unit(), BuildErrorUnit::Milliseconds);
+        assert!(Interval::builder().microseconds(i64::MAX).microseconds(1).microseconds(-1).try_build().is_ok());
+    }
+
+    #[test]
+    fn test_parse_error_kind() {
+        assert_eq!(Interval::new("1 monthss").unwrap_err().kind(), ParseErrorKind::InvalidInterval);
+        assert_eq!(Interval::new("abc days").unwrap_err().kind(), ParseErrorKind::ParseFloat);
+        assert_eq!(Interval::new("100000000000 years").unwrap_err().kind(), ParseErrorKind::InvalidYearMonth);
+        assert_eq!(Interval::new("100000000000000 hours").unwrap_err().kind(), ParseErrorKind::InvalidTime);
+    }
+
+    #[test]
+    fn test_interval_new_strict() {
+        assert_eq!(Interval::new_strict("1 mons 2 days 3 seconds").unwrap(), Interval::new("1 mons 2 days 3 seconds").unwrap());
+        assert_eq!(Interval::new_strict("2147483647days").unwrap(), Interval::from_days(i32::MAX));
+        assert_eq!(Interval::new_strict("-2147483647 days").unwrap(), Interval::from_days(-i32::MAX));
+
+        assert_eq!(Interval::new("3000000000 days").unwrap(), Interval::from_days(i32::MAX));
+        let err = Interval::new_strict("3000000000 days").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::Overflow);
+        assert_eq!(err.to_string(), "Invalid month/day interval overflow detected.");
+        assert_eq!(Interval::new_strict("1 hours -3000000000days").unwrap_err().kind(), ParseErrorKind::Overflow);
+        assert_eq!(Interval::new_strict("-2147483648 days").unwrap(), Interval::from_days(i32::MIN));
+
+        assert_eq!(Interval::new("3000000000 mons").unwrap(), Interval::from_months(i32::MAX));
+        assert_eq!(Interval::new_strict("3000000000 mons").unwrap_err().kind(), ParseErrorKind::Overflow);
+        assert_eq!(Interval::new_strict("3000000000 months").unwrap_err().kind(), ParseErrorKind::Overflow);
+        assert_eq!(Interval::new_strict("3000000000 years").unwrap_err().kind(), ParseErrorKind::Overflow);
+        assert_eq!(Interval::new_strict("178956970 years 8 mons").unwrap_err().kind(), ParseErrorKind::Overflow);
+
+        assert_eq!(Interval::new("2147483647 days 0.5 mons").unwrap(), Interval::from_days(i32::MAX));
+        let err = Interval::new_strict("2147483647 days 0.5 mons").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::Overflow);
+        assert_eq!(err.to_string(), "Invalid day interval overflow detected.");
+
+        assert_eq!(Interval::new("100000000000 hours").unwrap_err().kind(), ParseErrorKind::InvalidTime);
+        assert_eq!(Interval::new_strict("100000000000 hours").unwrap_err().kind(), ParseErrorKind::Overflow);
+        assert_eq!(Interval::new_strict("2562047788 hours 01:00").unwrap_err().kind(), ParseErrorKind::Overflow);
+        assert_eq!(Interval::new_strict("10000000000000000000 microseconds").unwrap_err().kind(), ParseErrorKind::Overflow);
+        assert_eq!(Interval::new_strict("04:60").unwrap_err().kind(), ParseErrorKind::InvalidTime);
+        assert_eq!(Interval::new_strict("abc days").unwrap_err().kind(), ParseErrorKind::ParseFloat);
+        assert_eq!(Interval::new_strict("1 monthss").unwrap_err().kind(), ParseErrorKind::InvalidInterval);
+    }
+
+    #[test]
+    fn test_parse_error_source() {
+        let err = Interval::new("abc days").unwrap_err();
+        assert!(err.source().is_some());
+        assert_eq!(err.source().unwrap().to_string(), "invalid float literal");
+        assert!(Interval::new("1 monthss").unwrap_err().source().is_none());
+    }
+
+    #[test]
+    fn test_interval_checked_add() {
+        let interval = Interval::from_components(1, 2, 3).checked_add(Interval::from_components(4, -5, 6));
+        assert_eq!(interval, Some(Interval::from_components(5, -3, 9)));
+        assert_eq!(Interval::from_components(i32::MAX, 0, 0).checked_add(Interval::from_months(1)), None);
+        assert_eq!(Interval::from_components(0, 0, i64::MAX).checked_add(Interval::from_components(0, 0, 1)), None);
+    }
+
+    #[test]
+    fn test_interval_checked_neg() {
+        assert_eq!(Interval::from_components(1, -2, 3).checked_neg(), Some(Interval::from_components(-1, 2, -3)));
+        assert_eq!(Interval::from_components(i32::MIN, 0, 0).checked_neg(), None);
+        assert_eq!(Interval::from_components(0, 0, i64::MIN).checked_neg(), None);
+    }
+
+    #[test]
+    fn test_interval_checked_sub() {
+        let interval = Interval::from_components(1, 2, 3).checked_sub(Interval::from_components(4, -5, 6));
+        assert_eq!(interval, Some(Interval::from_components(-3, 7, -3)));
+        assert_eq!(Interval::from_components(0, i32::MIN, 0).checked_sub(Interval::from_days(1)), None);
+        assert_eq!(Interval::from_components(0, 0, i64::MIN).checked_sub(Interval::from_components(0, 0, 1)), None);
+    }
+
+    #[test]
+    fn test_interval_saturating_add() {
+        let interval = Interval::from_components(1, 2, i64::MAX - 1).saturating_add(Interval::from_components(1, 2, 5));
+        assert_eq!(interval, Interval::from_components(2, 4, i64::MAX));
+        let interval = Interval::from_components(i32::MIN, 0, 0).saturating_add(Interval::from_months(-1));
+        assert_eq!(interval, Interval::from_months(i32::MIN));
+    }
+
+    #[test]
+    fn test_interval_saturating_sub() {
+        let interval = Interval::from_components(1, i32::MIN + 1, i64::MIN + 1).saturating_sub(Interval::from_components(1, 2, 5));
+        assert_eq!(interval, Interval::from_components(0, i32::MIN, i64::MIN));
+    }
+
+    #[test]
+    fn test_interval_overflowing_add() {
+        let interval = Interval::from_components(1, 2, 3).overflowing_add(Interval::from_components(4, -5, 6));
+        assert_eq!(interval, (Interval::from_components(5, -3, 9), false));
+        let interval = Interval::from_components(1, 0, i64::MAX).overflowing_add(Interval::from_components(1, 0, 1));
+        assert_eq!(interval, (Interval::from_components(2, 0, i64::MIN), true));
     }
 
     #[test]